@@ -6,19 +6,11 @@ set -e
 
 echo "Running LineGuard checks..."
 
-# Check only staged files
-FILES=$(git diff --cached --name-only --diff-filter=ACM)
+# Check the staged content of staged files, i.e. exactly what will be committed
+lineguard --staged --quiet
 
-if [ -z "$FILES" ]; then
-    echo "No files to check"
-    exit 0
-fi
-
-# Run LineGuard on staged files
-echo "$FILES" | lineguard --stdin --quiet
-
-# Optional: Auto-fix issues
-# echo "$FILES" | lineguard --stdin --fix
-# git add $FILES
+# Optional: Auto-fix issues and re-stage the fixed files
+# (partially staged files are refused rather than fixed)
+# lineguard --staged --fix
 
 echo "LineGuard checks passed!"
@@ -0,0 +1,341 @@
+//! Baseline files for grandfathering existing issues
+//!
+//! A baseline records each known issue by file, rule and a hash of the content
+//! of its line rather than the line number, so entries keep matching when
+//! unrelated lines are added or removed above them.
+
+use crate::CheckResult;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path};
+
+/// Version of the baseline file format
+const BASELINE_VERSION: u32 = 1;
+
+/// A single known issue
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Path of the file, with `/` separators
+    pub file: String,
+    /// Rule name, as reported in JSON output
+    pub rule: String,
+    /// Hash of the line content, absent for file-level issues
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    issues: Vec<BaselineEntry>,
+}
+
+/// Outcome of subtracting the baseline from one file's results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BaselineMatch {
+    /// Issues that were in the baseline and have been removed from the result
+    pub matched: usize,
+    /// Baseline entries for the file that no longer match any issue
+    pub fixed: usize,
+}
+
+/// Known issues loaded from a baseline file, grouped by file
+#[derive(Debug, Default)]
+pub struct Baseline {
+    files: HashMap<String, Vec<BaselineEntry>>,
+}
+
+impl Baseline {
+    /// Load a baseline file
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline file {}", path.display()))?;
+        let baseline: BaselineFile = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse baseline file {}", path.display()))?;
+        if baseline.version != BASELINE_VERSION {
+            anyhow::bail!(
+                "Unsupported baseline version {} in {}",
+                baseline.version,
+                path.display()
+            );
+        }
+
+        let mut files: HashMap<String, Vec<BaselineEntry>> = HashMap::new();
+        for entry in baseline.issues {
+            files.entry(entry.file.clone()).or_default().push(entry);
+        }
+        Ok(Self { files })
+    }
+
+    /// Write `entries` as a baseline file, sorted so the output is stable
+    pub fn write(path: &Path, mut entries: Vec<BaselineEntry>) -> anyhow::Result<()> {
+        entries.sort();
+        let baseline = BaselineFile {
+            version: BASELINE_VERSION,
+            issues: entries,
+        };
+        let mut json = serde_json::to_string_pretty(&baseline)?;
+        json.push('\n');
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write baseline file {}", path.display()))
+    }
+
+    /// Total number of entries in the baseline
+    pub fn len(&self) -> usize {
+        self.files.values().map(Vec::len).sum()
+    }
+
+    /// Whether the baseline has no entries
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Remove the issues of `result` that are in the baseline
+    ///
+    /// `content` is the content the issues were found in. Each baseline entry
+    /// matches at most one issue, so new copies of a known issue are still
+    /// reported.
+    pub fn apply(&self, result: &mut CheckResult, content: &[u8]) -> BaselineMatch {
+        let Some(known) = self.files.get(&file_key(&result.file_path)) else {
+            return BaselineMatch::default();
+        };
+
+        let mut remaining: HashMap<&BaselineEntry, usize> = HashMap::new();
+        for entry in known {
+            *remaining.entry(entry).or_default() += 1;
+        }
+
+        let entries = entries_for(result, content);
+        let mut matched = 0;
+        result.issues = std::mem::take(&mut result.issues)
+            .into_iter()
+            .zip(entries)
+            .filter(|(issue, entry)| {
+                // Entries recorded before a rule was split out still match
+                let legacy = issue.issue_type.split_from().map(|rule| BaselineEntry {
+                    rule: rule.name().to_string(),
+                    ..entry.clone()
+                });
+                for key in std::iter::once(entry).chain(&legacy) {
+                    if let Some(count) = remaining.get_mut(key)
+                        && *count > 0
+                    {
+                        *count -= 1;
+                        matched += 1;
+                        return false;
+                    }
+                }
+                true
+            })
+            .map(|(issue, _)| issue)
+            .collect();
+
+        BaselineMatch {
+            matched,
+            fixed: known.len() - matched,
+        }
+    }
+}
+
+/// Baseline entries for every issue of `result`, in the same order
+pub fn entries_for(result: &CheckResult, content: &[u8]) -> Vec<BaselineEntry> {
+    let file = file_key(&result.file_path);
+    let content = String::from_utf8_lossy(content);
+    let lines: Vec<&str> = content.lines().collect();
+
+    result
+        .issues
+        .iter()
+        .map(|issue| BaselineEntry {
+            file: file.clone(),
+            rule: issue.issue_type.name().to_string(),
+            hash: issue
+                .line
+                .map(|line| line_hash(lines.get(line - 1).copied().unwrap_or(""))),
+        })
+        .collect()
+}
+
+/// Stable key for a path: relative to the current directory when possible,
+/// without `./` and with `/` separators
+fn file_key(path: &Path) -> String {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let path = path.strip_prefix(&cwd).unwrap_or(path);
+
+    path.components()
+        .filter_map(|component| match component {
+            Component::CurDir => None,
+            // Joining after an empty segment keeps the leading `/`
+            Component::RootDir => Some("".into()),
+            other => Some(other.as_os_str().to_string_lossy()),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// FNV-1a hash of a line, ignoring trailing whitespace
+///
+/// Trailing whitespace is ignored so that changing how much of it a line has
+/// does not turn a known issue into a new one.
+fn line_hash(line: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in line.trim_end().bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Issue, IssueType, Severity};
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn result(path: &str, issues: &[(IssueType, Option<usize>)]) -> CheckResult {
+        CheckResult {
+            file_path: PathBuf::from(path),
+            issues: issues
+                .iter()
+                .map(|&(issue_type, line)| Issue {
+                    issue_type,
+                    line,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
+                    message: String::new(),
+                })
+                .collect(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_line_hash_ignores_trailing_whitespace() {
+        assert_eq!(line_hash("let x = 1;  "), line_hash("let x = 1;"));
+        assert_ne!(line_hash("let x = 1;"), line_hash("let x = 2;"));
+        assert_eq!(line_hash(""), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn test_file_key_normalises_paths() {
+        assert_eq!(file_key(Path::new("./src/main.rs")), "src/main.rs");
+        let cwd = std::env::current_dir().unwrap();
+        assert_eq!(file_key(&cwd.join("src").join("lib.rs")), "src/lib.rs");
+    }
+
+    #[test]
+    fn test_entries_for_issues() {
+        let result = result(
+            "a.txt",
+            &[
+                (IssueType::TrailingSpace, Some(2)),
+                (IssueType::MissingNewline, None),
+            ],
+        );
+        let entries = entries_for(&result, b"first\nsecond  \nthird");
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].rule, "trailing_space");
+        assert_eq!(entries[0].hash, Some(line_hash("second")));
+        assert_eq!(entries[1].rule, "missing_newline");
+        assert_eq!(entries[1].hash, None);
+    }
+
+    #[test]
+    fn test_apply_survives_line_moves() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+
+        let old = result("a.txt", &[(IssueType::TrailingSpace, Some(1))]);
+        Baseline::write(&path, entries_for(&old, b"old  \n")).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        assert_eq!(baseline.len(), 1);
+
+        // The known issue moved down a line and a new one appeared
+        let mut new = result(
+            "a.txt",
+            &[
+                (IssueType::TrailingSpace, Some(1)),
+                (IssueType::TrailingSpace, Some(2)),
+            ],
+        );
+        let outcome = baseline.apply(&mut new, b"new  \nold  \n");
+
+        assert_eq!(
+            outcome,
+            BaselineMatch {
+                matched: 1,
+                fixed: 0
+            }
+        );
+        assert_eq!(new.issues.len(), 1);
+        assert_eq!(new.issues[0].line, Some(1));
+    }
+
+    #[test]
+    fn test_apply_counts_fixed_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+
+        let old = result(
+            "a.txt",
+            &[
+                (IssueType::TrailingSpace, Some(1)),
+                (IssueType::TrailingSpace, Some(2)),
+            ],
+        );
+        Baseline::write(&path, entries_for(&old, b"x  \nx  \n")).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        // Each entry matches only once
+        let mut new = result("a.txt", &[(IssueType::TrailingSpace, Some(1))]);
+        let outcome = baseline.apply(&mut new, b"x  \ny\n");
+        assert_eq!(
+            outcome,
+            BaselineMatch {
+                matched: 1,
+                fixed: 1
+            }
+        );
+        assert!(new.issues.is_empty());
+
+        // Files without entries are left alone
+        let mut other = result("b.txt", &[(IssueType::TrailingSpace, Some(1))]);
+        assert_eq!(
+            baseline.apply(&mut other, b"x  \n"),
+            BaselineMatch::default()
+        );
+        assert_eq!(other.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_matches_entries_of_split_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+
+        // Whitespace-only lines were once reported as trailing whitespace
+        let old = result("a.txt", &[(IssueType::TrailingSpace, Some(2))]);
+        Baseline::write(&path, entries_for(&old, b"x\n   \n")).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        let mut new = result("a.txt", &[(IssueType::WhitespaceOnlyLine, Some(2))]);
+        let outcome = baseline.apply(&mut new, b"x\n   \n");
+        assert_eq!(outcome.matched, 1);
+        assert!(new.issues.is_empty());
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        fs::write(&path, r#"{"version": 2, "issues": []}"#).unwrap();
+
+        let error = Baseline::load(&path).unwrap_err();
+        assert!(error.to_string().contains("Unsupported baseline version"));
+    }
+}
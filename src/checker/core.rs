@@ -3,8 +3,210 @@
 //! This module contains the core logic for checking file content,
 //! implemented as pure functions for better testability.
 
-use crate::config::Config;
-use crate::{Issue, IssueType};
+use crate::checker::{Column, Suppressions, hazards};
+use crate::config::{BomPolicy, Config, IndentStyle, LineEndingStyle};
+use crate::{Issue, IssueType, Severity};
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthChar;
+
+/// Number of leading bytes inspected when sniffing a file for binary content
+pub const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Guess whether content is binary from a sample of its first bytes
+///
+/// Like git, any NUL byte marks the content as binary. Content is also treated
+/// as binary when more than 10% of the sample are control bytes that text
+/// files do not normally contain.
+pub fn is_binary_content(sample: &[u8]) -> bool {
+    if sample.contains(&0) {
+        return true;
+    }
+
+    let control_bytes = sample
+        .iter()
+        .filter(|&&b| {
+            (b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r' | 0x08 | 0x0c | 0x1b)) || b == 0x7f
+        })
+        .count();
+
+    control_bytes * 10 > sample.len()
+}
+
+/// UTF-8 encoding of the byte order mark U+FEFF
+pub const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// The encoding named by a UTF-16 byte order mark at the start of `bytes`
+pub fn utf16_bom(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\xFF\xFE") {
+        Some("UTF-16LE")
+    } else if bytes.starts_with(b"\xFE\xFF") {
+        Some("UTF-16BE")
+    } else {
+        None
+    }
+}
+
+/// Point the byte columns of an issue found in the lossy decoding of
+/// `raw_line` back at the raw bytes
+///
+/// Lossy decoding turns each invalid sequence into a three-byte U+FFFD, so
+/// byte offsets past one differ from those in the file. Character columns
+/// are left alone, as each invalid sequence counts as one character.
+pub fn restore_byte_columns(raw_line: &[u8], issue: &mut Issue) {
+    for column in [&mut issue.column, &mut issue.end_column]
+        .into_iter()
+        .flatten()
+    {
+        column.byte = raw_byte_offset(raw_line, column.byte - 1) + 1;
+    }
+}
+
+/// The offset in `raw` of byte offset `lossy` in its lossy decoding
+fn raw_byte_offset(raw: &[u8], lossy: usize) -> usize {
+    let (mut lossy_pos, mut raw_pos) = (0, 0);
+    for chunk in raw.utf8_chunks() {
+        let valid = chunk.valid().len();
+        if lossy < lossy_pos + valid {
+            break;
+        }
+        lossy_pos += valid;
+        raw_pos += valid;
+
+        if !chunk.invalid().is_empty() {
+            if lossy < lossy_pos + char::REPLACEMENT_CHARACTER.len_utf8() {
+                return raw_pos;
+            }
+            lossy_pos += char::REPLACEMENT_CHARACTER.len_utf8();
+            raw_pos += chunk.invalid().len();
+        }
+    }
+    raw_pos + (lossy - lossy_pos)
+}
+
+/// The merge conflict marker a line starts with, if any
+fn conflict_marker(line: &str) -> Option<&'static str> {
+    let marker = ["<<<<<<<", "|||||||", "=======", ">>>>>>>"]
+        .into_iter()
+        .find(|marker| line.starts_with(marker))?;
+    let rest = &line[marker.len()..];
+
+    let is_marker = if marker == "=======" {
+        rest.trim_end().is_empty()
+    } else {
+        rest.is_empty() || rest.starts_with([' ', '\t'])
+    };
+    is_marker.then_some(marker)
+}
+
+/// `line` without the byte order mark the first line may start with, and the
+/// byte offset of what remains
+fn without_bom(line: &str, line_number: usize) -> (&str, usize) {
+    match line.strip_prefix('\u{FEFF}') {
+        Some(rest) if line_number == 1 => (rest, line.len() - rest.len()),
+        _ => (line, 0),
+    }
+}
+
+/// Whether a line holds nothing but spaces and tabs
+fn is_blank(line: &str) -> bool {
+    line.trim_matches([' ', '\t']).is_empty()
+}
+
+/// The run of spaces and tabs a line starts with
+fn leading_indentation(line: &str) -> &str {
+    let len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    &line[..len]
+}
+
+/// Whether the indentation of `line` has spaces other than fewer than
+/// `indent_size` alignment spaces after at least one tab
+///
+/// The single space aligning the ` *` of a top-level block comment is allowed.
+fn has_space_run(line: &str, indent: &str, indent_size: usize) -> bool {
+    let is_comment_continuation = indent == " " && line[1..].starts_with('*');
+    (indent.starts_with(' ') && !is_comment_continuation)
+        || indent.contains(" \t")
+        || indent.contains(&" ".repeat(indent_size))
+}
+
+/// Whether a line holds nothing but a URL, optionally after a comment marker
+/// or list bullet
+fn is_url_line(line: &str) -> bool {
+    let rest = line
+        .trim()
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    rest.contains("://") && !rest.contains(char::is_whitespace)
+}
+
+/// Line terminator found at the end of a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+impl LineEnding {
+    /// Detect the terminator of a line that still includes it
+    pub fn detect(line: &str) -> Option<Self> {
+        if line.ends_with("\r\n") {
+            Some(LineEnding::Crlf)
+        } else if line.ends_with('\n') {
+            Some(LineEnding::Lf)
+        } else {
+            None
+        }
+    }
+
+    /// Detect the terminator of a raw line that still includes it
+    pub fn detect_bytes(line: &[u8]) -> Option<Self> {
+        if line.ends_with(b"\r\n") {
+            Some(LineEnding::Crlf)
+        } else if line.ends_with(b"\n") {
+            Some(LineEnding::Lf)
+        } else {
+            None
+        }
+    }
+
+    /// Strip the terminator from a line, the same way `str::lines` does
+    pub fn strip(line: &str) -> &str {
+        match line.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => line,
+        }
+    }
+
+    /// Strip the terminator from a raw line
+    pub fn strip_bytes(line: &[u8]) -> &[u8] {
+        match line.strip_suffix(b"\n") {
+            Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+            None => line,
+        }
+    }
+
+    /// The terminator itself
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+        }
+    }
+
+    /// The terminator itself as bytes
+    pub fn as_bytes(self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Human-readable name of the terminator
+    pub fn name(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "LF",
+            LineEnding::Crlf => "CRLF",
+        }
+    }
+}
 
 /// Core checker implementation with pure functions
 pub struct CheckerCore {
@@ -34,12 +236,20 @@ impl CheckerCore {
             Some(Issue {
                 issue_type: IssueType::MissingNewline,
                 line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Missing newline at end of file".to_string(),
             })
-        } else if content.ends_with("\n\n") {
+        } else if content.ends_with("\n\n") || content.ends_with("\n\r\n") {
             Some(Issue {
                 issue_type: IssueType::MultipleNewlines,
                 line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Multiple newlines at end of file".to_string(),
             })
         } else {
@@ -48,26 +258,61 @@ impl CheckerCore {
     }
 
     /// Check a single line for trailing whitespace
+    ///
+    /// Lines holding nothing but whitespace are reported by their own rule.
+    /// With `markdown_hard_breaks`, exactly two trailing spaces after content
+    /// are a hard line break and not reported. Allowed control characters,
+    /// such as form feed, do not count as whitespace.
     pub fn check_line_trailing_whitespace(&self, line: &str, line_number: usize) -> Option<Issue> {
-        if !self.config.checks.trailing_spaces {
+        let checks = &self.config.checks;
+        let trimmed = line.trim_end_matches(|c: char| {
+            c.is_whitespace() && !checks.allowed_control_characters.contains(&c)
+        });
+        let trailing = &line[trimmed.len()..];
+        if trailing.is_empty() {
             return None;
         }
 
-        let trimmed = line.trim_end();
-        if trimmed.len() < line.len() {
-            Some(Issue {
-                issue_type: IssueType::TrailingSpace,
-                line: Some(line_number),
-                message: "Trailing spaces found".to_string(),
-            })
-        } else {
-            None
+        let whitespace_only = trimmed.is_empty();
+        if whitespace_only && !checks.reports_whitespace_only_lines()
+            || !whitespace_only && !checks.trailing_spaces
+            || !whitespace_only && checks.markdown_hard_breaks && trailing == "  "
+        {
+            return None;
         }
+
+        // Name whitespace that does not look like whitespace, such as NBSP
+        let unusual: Vec<String> = trailing
+            .chars()
+            .filter(|c| !c.is_ascii())
+            .map(hazards::describe)
+            .collect();
+        let (issue_type, message) = if whitespace_only {
+            (IssueType::WhitespaceOnlyLine, "Whitespace-only line found")
+        } else {
+            (IssueType::TrailingSpace, "Trailing spaces found")
+        };
+        let message = if unusual.is_empty() {
+            message.to_string()
+        } else {
+            format!("{message} (including {})", unusual.join(", "))
+        };
+
+        Some(Issue {
+            issue_type,
+            line: Some(line_number),
+            end_line: None,
+            column: Some(Column::at(line, trimmed.len())),
+            end_column: Some(Column::at(line, line.len())),
+            severity: Severity::Error,
+            message,
+        })
     }
 
     /// Check all lines in content for trailing whitespace
     pub fn check_content_trailing_whitespace(&self, content: &str) -> Vec<Issue> {
-        if !self.config.checks.trailing_spaces {
+        let checks = &self.config.checks;
+        if !checks.trailing_spaces && !checks.reports_whitespace_only_lines() {
             return Vec::new();
         }
 
@@ -80,14 +325,590 @@ impl CheckerCore {
         issues
     }
 
-    /// Check content for all issues
-    pub fn check_content(&self, content: &str) -> Vec<Issue> {
+    /// Check the terminator of a single line against the configured line ending style
+    ///
+    /// `line` must still include its terminator. `first_ending` holds the first
+    /// terminator seen in the file and is updated as lines are checked, so that
+    /// the `consistent` style can be checked one line at a time.
+    pub fn check_line_ending(
+        &self,
+        line: &str,
+        line_number: usize,
+        first_ending: &mut Option<LineEnding>,
+    ) -> Vec<Issue> {
+        let Some(style) = self.config.checks.line_ending else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+
+        if LineEnding::strip(line).contains('\r') {
+            issues.push(Issue {
+                issue_type: IssueType::LoneCarriageReturn,
+                line: Some(line_number),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "Lone carriage return (CR) found".to_string(),
+            });
+        }
+
+        let Some(ending) = LineEnding::detect(line) else {
+            return issues;
+        };
+
+        match (style.resolve(), ending) {
+            (LineEndingStyle::Lf, LineEnding::Crlf) => issues.push(Issue {
+                issue_type: IssueType::CrlfLineEnding,
+                line: Some(line_number),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "CRLF line ending found (expected LF)".to_string(),
+            }),
+            (LineEndingStyle::Crlf, LineEnding::Lf) => issues.push(Issue {
+                issue_type: IssueType::LfLineEnding,
+                line: Some(line_number),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "LF line ending found (expected CRLF)".to_string(),
+            }),
+            (LineEndingStyle::Consistent, _) => match *first_ending {
+                None => *first_ending = Some(ending),
+                Some(expected) if expected != ending => issues.push(Issue {
+                    issue_type: IssueType::MixedLineEndings,
+                    line: Some(line_number),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
+                    message: format!(
+                        "Mixed line endings: {} found in a file using {}",
+                        ending.name(),
+                        expected.name()
+                    ),
+                }),
+                Some(_) => {},
+            },
+            _ => {},
+        }
+
+        issues
+    }
+
+    /// Check all lines in content for line ending issues
+    pub fn check_content_line_endings(&self, content: &str) -> Vec<Issue> {
+        if self.config.checks.line_ending.is_none() {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        let mut first_ending = None;
+        for (line_num, line) in content.split_inclusive('\n').enumerate() {
+            issues.extend(self.check_line_ending(line, line_num + 1, &mut first_ending));
+        }
+        issues
+    }
+
+    /// Check a single line, without its terminator, against `max_line_length`
+    ///
+    /// Width is measured in display columns: East Asian wide characters take
+    /// two columns and tabs advance to the next multiple of `tab_width`.
+    pub fn check_line_length(&self, line: &str, line_number: usize) -> Option<Issue> {
+        let checks = &self.config.checks;
+        let max = checks.max_line_length?;
+        let tab_width = checks.tab_width.max(1);
+
+        let mut width = 0;
+        let mut overflow = None;
+        for (byte, c) in line.char_indices() {
+            width = match c {
+                '\t' => (width / tab_width + 1) * tab_width,
+                _ => width + c.width().unwrap_or(0),
+            };
+            if width > max && overflow.is_none() {
+                overflow = Some(byte);
+            }
+        }
+
+        let overflow = overflow?;
+        if checks.line_length_ignore_urls && is_url_line(line) {
+            return None;
+        }
+        if let Some(pattern) = &checks.line_length_ignore_pattern
+            && pattern.is_match(line)
+        {
+            return None;
+        }
+
+        Some(Issue {
+            issue_type: IssueType::LineTooLong,
+            line: Some(line_number),
+            end_line: None,
+            column: Some(Column::at(line, overflow)),
+            end_column: Some(Column::at(line, line.len())),
+            severity: Severity::Error,
+            message: format!("Line too long ({width} > {max} columns)"),
+        })
+    }
+
+    /// Check all lines in content against `max_line_length`
+    pub fn check_content_line_length(&self, content: &str) -> Vec<Issue> {
+        if self.config.checks.max_line_length.is_none() {
+            return Vec::new();
+        }
+
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| self.check_line_length(line, line_num + 1))
+            .collect()
+    }
+
+    /// Check the leading whitespace of a single line, without its terminator,
+    /// against `indent_style` and `mixed_indentation`
+    ///
+    /// Lines holding only whitespace are left to the trailing space check.
+    /// Under the `tabs` style, fewer than `indent_size` spaces after the tabs
+    /// are allowed for alignment.
+    pub fn check_line_indentation(&self, line: &str, line_number: usize) -> Option<Issue> {
+        let checks = &self.config.checks;
+        if checks.indent_style.is_none() && !checks.mixed_indentation {
+            return None;
+        }
+
+        let indent = leading_indentation(line);
+        if indent.len() == line.len() {
+            return None;
+        }
+
+        let has_tabs = indent.contains('\t');
+        let has_spaces = indent.contains(' ');
+        let (issue_type, message) = if checks.mixed_indentation && has_tabs && has_spaces {
+            (
+                IssueType::MixedIndentation,
+                "Mixed tabs and spaces in indentation",
+            )
+        } else {
+            match checks.indent_style? {
+                IndentStyle::Spaces if has_tabs => (
+                    IssueType::TabIndentation,
+                    "Tab indentation found (expected spaces)",
+                ),
+                IndentStyle::Tabs if has_space_run(line, indent, checks.indent_size.max(1)) => (
+                    IssueType::SpaceIndentation,
+                    "Space indentation found (expected tabs)",
+                ),
+                _ => return None,
+            }
+        };
+
+        Some(Issue {
+            issue_type,
+            line: Some(line_number),
+            end_line: None,
+            column: Some(Column::at(line, 0)),
+            end_column: Some(Column::at(line, indent.len())),
+            severity: Severity::Error,
+            message: message.to_string(),
+        })
+    }
+
+    /// Check the indentation of all lines in content
+    pub fn check_content_indentation(&self, content: &str) -> Vec<Issue> {
+        let checks = &self.config.checks;
+        if checks.indent_style.is_none() && !checks.mixed_indentation {
+            return Vec::new();
+        }
+
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| self.check_line_indentation(line, line_num + 1))
+            .collect()
+    }
+
+    /// Check a single line, without its terminator, for Unicode hazards
+    ///
+    /// Zero-width joiners between two non-ASCII characters are allowed, as
+    /// emoji sequences and some scripts need them. A U+FEFF at the very start
+    /// of the file is a byte order mark, not a hazard.
+    pub fn check_line_unicode_hazards(&self, line: &str, line_number: usize) -> Vec<Issue> {
+        if !self.config.checks.unicode_hazards || line.is_ascii() {
+            return Vec::new();
+        }
+
+        line.char_indices()
+            .filter_map(|(byte, c)| {
+                let (issue_type, name) = hazards::classify(c)?;
+                let is_bom = c == '\u{FEFF}' && line_number == 1 && byte == 0;
+                let is_joiner =
+                    matches!(c, '\u{200C}' | '\u{200D}') && hazards::is_joining(line, byte, c);
+                if is_bom || is_joiner {
+                    return None;
+                }
+
+                Some(Issue {
+                    issue_type,
+                    line: Some(line_number),
+                    end_line: None,
+                    column: Some(Column::at(line, byte)),
+                    end_column: Some(Column::at(line, byte + c.len_utf8())),
+                    severity: Severity::Error,
+                    message: format!("U+{:04X} {name} found", u32::from(c)),
+                })
+            })
+            .collect()
+    }
+
+    /// Check all lines in content for Unicode hazards
+    pub fn check_content_unicode_hazards(&self, content: &str) -> Vec<Issue> {
+        if !self.config.checks.unicode_hazards || content.is_ascii() {
+            return Vec::new();
+        }
+
+        content
+            .lines()
+            .enumerate()
+            .flat_map(|(line_num, line)| self.check_line_unicode_hazards(line, line_num + 1))
+            .collect()
+    }
+
+    /// Check a single line for stray control characters
+    ///
+    /// `line` must not include its terminator. Carriage returns are only
+    /// reported here when no `line_ending` style is set, otherwise the line
+    /// ending check reports them. A line is reported once, at its first stray
+    /// control character, naming each distinct one, so colour-coded logs do
+    /// not add an issue per escape sequence.
+    pub fn check_line_control_characters(&self, line: &str, line_number: usize) -> Option<Issue> {
+        let checks = &self.config.checks;
+        if !checks.control_characters {
+            return None;
+        }
+
+        let report_cr = checks.line_ending.is_none();
+        let mut found = line.char_indices().filter(|&(_, c)| {
+            hazards::is_stray_control(c, &checks.allowed_control_characters, report_cr)
+        });
+        let (byte, first) = found.next()?;
+
+        let mut count = 1;
+        let mut names = vec![first];
+        for (_, c) in found {
+            count += 1;
+            if !names.contains(&c) {
+                names.push(c);
+            }
+        }
+        let names: Vec<String> = names.into_iter().map(hazards::describe).collect();
+        let message = match count {
+            1 => format!("{} found", names[0]),
+            _ => format!("{} found ({count} occurrences)", names.join(", ")),
+        };
+
+        Some(Issue {
+            issue_type: IssueType::ControlCharacter,
+            line: Some(line_number),
+            end_line: None,
+            column: Some(Column::at(line, byte)),
+            end_column: Some(Column::at(line, byte + first.len_utf8())),
+            severity: Severity::Error,
+            message,
+        })
+    }
+
+    /// Check all lines in content for stray control characters
+    pub fn check_content_control_characters(&self, content: &str) -> Vec<Issue> {
+        if !self.config.checks.control_characters {
+            return Vec::new();
+        }
+
+        content
+            .lines()
+            .enumerate()
+            .flat_map(|(line_num, line)| self.check_line_control_characters(line, line_num + 1))
+            .collect()
+    }
+
+    /// Check a single line for a merge conflict marker
+    ///
+    /// Markers must be exactly seven characters wide and start the line. The
+    /// `=======` separator must be alone on its line, the others may be
+    /// followed by a space and a label, so longer runs such as Markdown
+    /// setext underlines and reStructuredText adornments are not reported.
+    /// `in_conflict` tracks whether a `<<<<<<<` block is open: the `|||||||`
+    /// and `=======` markers are only reported inside one, so a seven-character
+    /// setext underline is not either.
+    pub fn check_line_conflict_marker(
+        &self,
+        line: &str,
+        line_number: usize,
+        in_conflict: &mut bool,
+    ) -> Option<Issue> {
+        if !self.config.checks.conflict_markers {
+            return None;
+        }
+
+        let (rest, offset) = without_bom(line, line_number);
+        let marker = conflict_marker(rest)?;
+        match marker {
+            "<<<<<<<" => *in_conflict = true,
+            ">>>>>>>" => *in_conflict = false,
+            _ if !*in_conflict => return None,
+            _ => {},
+        }
+
+        Some(Issue {
+            issue_type: IssueType::ConflictMarker,
+            line: Some(line_number),
+            end_line: None,
+            column: Some(Column::at(line, offset)),
+            end_column: Some(Column::at(line, offset + marker.len())),
+            severity: Severity::Error,
+            message: format!("Merge conflict marker found ({marker})"),
+        })
+    }
+
+    /// Check all lines in content for merge conflict markers
+    pub fn check_content_conflict_markers(&self, content: &str) -> Vec<Issue> {
+        if !self.config.checks.conflict_markers {
+            return Vec::new();
+        }
+
+        let mut in_conflict = false;
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| {
+                self.check_line_conflict_marker(line, line_num + 1, &mut in_conflict)
+            })
+            .collect()
+    }
+
+    /// Track runs of blank lines, one line at a time
+    ///
+    /// `run_start` holds the first line of the current run. A run is reported
+    /// when the line ending it is seen, so blank lines at the end of the file
+    /// are left to the newline ending check.
+    pub fn check_line_blank(
+        &self,
+        line: &str,
+        line_number: usize,
+        run_start: &mut Option<usize>,
+    ) -> Vec<Issue> {
+        if is_blank(without_bom(line, line_number).0) {
+            run_start.get_or_insert(line_number);
+            return Vec::new();
+        }
+
+        match run_start.take() {
+            Some(start) => self.check_blank_run(start, line_number - 1),
+            None => Vec::new(),
+        }
+    }
+
+    /// Check all lines in content for excess and leading blank lines
+    pub fn check_content_blank_lines(&self, content: &str) -> Vec<Issue> {
+        let checks = &self.config.checks;
+        if checks.max_consecutive_blank_lines.is_none() && !checks.leading_blank_lines {
+            return Vec::new();
+        }
+
+        let mut run_start = None;
+        content
+            .lines()
+            .enumerate()
+            .flat_map(|(line_num, line)| self.check_line_blank(line, line_num + 1, &mut run_start))
+            .collect()
+    }
+
+    /// Check a run of blank lines from `start` to `end`, inclusive
+    fn check_blank_run(&self, start: usize, end: usize) -> Vec<Issue> {
+        let count = end - start + 1;
+        let issue = |issue_type, message| Issue {
+            issue_type,
+            line: Some(start),
+            end_line: (end > start).then_some(end),
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message,
+        };
+
         let mut issues = Vec::new();
+        if start == 1 && self.config.checks.leading_blank_lines {
+            let message = match count {
+                1 => "Blank line at start of file".to_string(),
+                _ => format!("{count} blank lines at start of file"),
+            };
+            issues.push(issue(IssueType::LeadingBlankLines, message));
+        }
+        if let Some(max) = self.config.checks.max_consecutive_blank_lines
+            && count > max
+        {
+            issues.push(issue(
+                IssueType::ConsecutiveBlankLines,
+                format!("{count} consecutive blank lines (max {max})"),
+            ));
+        }
+        issues
+    }
+
+    /// Report the first invalid UTF-8 byte, given its offset in the file
+    pub fn check_utf8(&self, offset: usize, line_number: usize) -> Option<Issue> {
+        if !self.config.checks.require_utf8 {
+            return None;
+        }
+
+        Some(Issue {
+            issue_type: IssueType::InvalidUtf8,
+            line: Some(line_number),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: format!("Invalid UTF-8 at byte offset {offset}"),
+        })
+    }
+
+    /// Check the start of a file against the byte order mark policy
+    ///
+    /// UTF-16 byte order marks are reported whatever the policy, since the
+    /// rest of the file cannot be checked as text, unless `.editorconfig`
+    /// declares a UTF-16 charset.
+    pub fn check_bom(&self, start: &[u8]) -> Option<Issue> {
+        let checks = &self.config.checks;
+        let policy = checks.bom;
+
+        let (issue_type, message) = if let Some(encoding) = utf16_bom(start) {
+            if checks.allow_utf16 {
+                return None;
+            }
+            (
+                IssueType::Utf16Bom,
+                format!("{encoding} byte order mark found; file is not UTF-8"),
+            )
+        } else if start.starts_with(UTF8_BOM) {
+            if policy != BomPolicy::Forbid {
+                return None;
+            }
+            (
+                IssueType::UnexpectedBom,
+                "UTF-8 byte order mark found".to_string(),
+            )
+        } else {
+            if policy != BomPolicy::Require || start.is_empty() {
+                return None;
+            }
+            (
+                IssueType::MissingBom,
+                "Missing UTF-8 byte order mark".to_string(),
+            )
+        };
+
+        Some(Issue {
+            issue_type,
+            line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message,
+        })
+    }
+
+    /// Check raw file content for all issues
+    ///
+    /// Content that is not valid UTF-8 (Latin-1, Shift-JIS, Windows-1252, ...)
+    /// is checked through a lossy decoding. Lossy decoding keeps every ASCII
+    /// byte intact, and these encodings never use space, tab, CR or LF inside
+    /// a multi-byte character, so whitespace and newline checks still see the
+    /// original bytes.
+    pub fn check_bytes(&self, bytes: &[u8]) -> Vec<Issue> {
+        let mut issues: Vec<Issue> = self.check_bom(bytes).into_iter().collect();
+        // UTF-16 text is not meaningful to the line checks
+        if utf16_bom(bytes).is_some() {
+            return self.filter_issues(&Suppressions::new(), issues);
+        }
+
+        let content = match std::str::from_utf8(bytes) {
+            Ok(content) => Cow::Borrowed(content),
+            Err(e) => {
+                let offset = e.valid_up_to();
+                let line_number = bytes[..offset].iter().filter(|&&b| b == b'\n').count() + 1;
+                issues.extend(self.check_utf8(offset, line_number));
+                String::from_utf8_lossy(bytes)
+            },
+        };
+
+        let found = issues.len();
+        issues.extend(self.find_issues(&content));
+        if let Cow::Owned(_) = content {
+            let raw_lines: Vec<&[u8]> = bytes.split(|&b| b == b'\n').collect();
+            for issue in &mut issues[found..] {
+                if let Some(raw_line) = issue.line.and_then(|line| raw_lines.get(line - 1))
+                    && std::str::from_utf8(raw_line).is_err()
+                {
+                    restore_byte_columns(raw_line, issue);
+                }
+            }
+        }
+        self.filter_issues(&Suppressions::parse(&content), issues)
+    }
+
+    /// Check content for all issues, honouring inline suppression comments
+    pub fn check_content(&self, content: &str) -> Vec<Issue> {
+        let issues = self.find_issues(content);
+        self.filter_issues(&Suppressions::parse(content), issues)
+    }
 
-        // Check trailing whitespace
-        if self.config.checks.trailing_spaces {
-            issues.extend(self.check_content_trailing_whitespace(content));
+    /// Drop issues silenced by suppression comments or not selected by the
+    /// configuration, reporting unused suppressions if configured, and give
+    /// each remaining issue its configured severity
+    ///
+    /// Suppressions are applied first, so a comment silencing a rule that is
+    /// not selected still counts as used.
+    pub fn filter_issues(&self, suppressions: &Suppressions, issues: Vec<Issue>) -> Vec<Issue> {
+        let mut issues = suppressions.filter(issues, self.config.report_unused_suppressions);
+        issues.retain(|issue| self.config.is_rule_selected(issue.issue_type));
+        for issue in &mut issues {
+            issue.severity = self.config.severity_for(issue.issue_type);
         }
+        issues
+    }
+
+    /// Run every enabled check over content
+    fn find_issues(&self, content: &str) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        // Check trailing whitespace and whitespace-only lines
+        issues.extend(self.check_content_trailing_whitespace(content));
+
+        // Check line endings
+        issues.extend(self.check_content_line_endings(content));
+
+        // Check line length
+        issues.extend(self.check_content_line_length(content));
+
+        // Check indentation
+        issues.extend(self.check_content_indentation(content));
+
+        // Check for invisible and bidirectional control characters
+        issues.extend(self.check_content_unicode_hazards(content));
+
+        // Check for stray control characters
+        issues.extend(self.check_content_control_characters(content));
+
+        // Check for merge conflict markers
+        issues.extend(self.check_content_conflict_markers(content));
+
+        // Check for excess and leading blank lines
+        issues.extend(self.check_content_blank_lines(content));
 
         // Check newline ending
         if let Some(issue) = self.check_newline_ending(content) {
@@ -123,6 +944,14 @@ mod tests {
         assert_eq!(issue.unwrap().issue_type, IssueType::MultipleNewlines);
     }
 
+    #[test]
+    fn test_check_newline_ending_multiple_crlf() {
+        let checker = CheckerCore::new(Config::default());
+        let issue = checker.check_newline_ending("content\r\n\r\n");
+        assert_eq!(issue.unwrap().issue_type, IssueType::MultipleNewlines);
+        assert!(checker.check_newline_ending("content\r\n").is_none());
+    }
+
     #[test]
     fn test_check_newline_ending_disabled() {
         let mut config = Config::default();
@@ -152,6 +981,46 @@ mod tests {
         assert!(issue.is_some());
     }
 
+    #[test]
+    fn test_check_line_trailing_whitespace_columns() {
+        let checker = CheckerCore::new(Config::default());
+
+        let issue = checker.check_line_trailing_whitespace("text  ", 1).unwrap();
+        assert_eq!(
+            issue.column,
+            Some(Column {
+                character: 5,
+                byte: 5
+            })
+        );
+        assert_eq!(
+            issue.end_column,
+            Some(Column {
+                character: 7,
+                byte: 7
+            })
+        );
+
+        // Character and byte columns diverge after multi-byte characters
+        let issue = checker
+            .check_line_trailing_whitespace("héllo\t", 1)
+            .unwrap();
+        assert_eq!(
+            issue.column,
+            Some(Column {
+                character: 6,
+                byte: 7
+            })
+        );
+        assert_eq!(
+            issue.end_column,
+            Some(Column {
+                character: 7,
+                byte: 8
+            })
+        );
+    }
+
     #[test]
     fn test_check_line_trailing_whitespace_disabled() {
         let mut config = Config::default();
@@ -223,19 +1092,647 @@ mod tests {
         assert_eq!(issues.len(), 2); // trailing spaces + missing newline
     }
 
+    fn line_ending_checker(style: LineEndingStyle) -> CheckerCore {
+        let mut config = Config::default();
+        config.checks.line_ending = Some(style);
+        CheckerCore::new(config)
+    }
+
     #[test]
-    fn test_checker_core_config_getter() {
-        let config = Config::default();
-        let checker = CheckerCore::new(config.clone());
+    fn test_line_ending_detect_and_strip() {
+        assert_eq!(LineEnding::detect("a\n"), Some(LineEnding::Lf));
+        assert_eq!(LineEnding::detect("a\r\n"), Some(LineEnding::Crlf));
+        assert_eq!(LineEnding::detect("a"), None);
+        assert_eq!(LineEnding::strip("a\r\n"), "a");
+        assert_eq!(LineEnding::strip("a\n"), "a");
+        assert_eq!(LineEnding::strip("a\r"), "a\r");
+    }
 
-        // Test the config() getter
-        assert_eq!(
-            checker.config().checks.trailing_spaces,
-            config.checks.trailing_spaces
+    #[test]
+    fn test_check_line_endings_disabled_by_default() {
+        let checker = CheckerCore::new(Config::default());
+        assert!(
+            checker
+                .check_content_line_endings("a\r\nb\nc\rd\n")
+                .is_empty()
         );
-        assert_eq!(
+    }
+
+    #[test]
+    fn test_check_line_endings_lf() {
+        let checker = line_ending_checker(LineEndingStyle::Lf);
+        let issues = checker.check_content_line_endings("one\ntwo\r\nthree\n");
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::CrlfLineEnding);
+        assert_eq!(issues[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_check_line_endings_crlf() {
+        let checker = line_ending_checker(LineEndingStyle::Crlf);
+        let issues = checker.check_content_line_endings("one\r\ntwo\nthree");
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::LfLineEnding);
+        assert_eq!(issues[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_check_line_endings_consistent() {
+        let checker = line_ending_checker(LineEndingStyle::Consistent);
+        assert!(
+            checker
+                .check_content_line_endings("one\r\ntwo\r\n")
+                .is_empty()
+        );
+
+        let issues = checker.check_content_line_endings("one\r\ntwo\nthree\r\nfour\n");
+        assert_eq!(issues.len(), 2);
+        assert!(
+            issues
+                .iter()
+                .all(|i| i.issue_type == IssueType::MixedLineEndings)
+        );
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(issues[1].line, Some(4));
+    }
+
+    #[test]
+    fn test_check_line_endings_lone_cr() {
+        let checker = line_ending_checker(LineEndingStyle::Consistent);
+        let issues = checker.check_content_line_endings("one\ntwo\rthree\n");
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::LoneCarriageReturn);
+        assert_eq!(issues[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_line_ending_style_native_resolves() {
+        let resolved = LineEndingStyle::Native.resolve();
+        if cfg!(windows) {
+            assert_eq!(resolved, LineEndingStyle::Crlf);
+        } else {
+            assert_eq!(resolved, LineEndingStyle::Lf);
+        }
+        assert_eq!(
+            LineEndingStyle::Consistent.resolve(),
+            LineEndingStyle::Consistent
+        );
+    }
+
+    fn line_length_checker(max: usize) -> CheckerCore {
+        let mut config = Config::default();
+        config.checks.max_line_length = Some(max);
+        CheckerCore::new(config)
+    }
+
+    #[test]
+    fn test_check_line_length_display_width() {
+        let checker = line_length_checker(10);
+        assert!(checker.check_line_length("0123456789", 1).is_none());
+
+        let issue = checker.check_line_length("0123456789ab", 3).unwrap();
+        assert_eq!(issue.issue_type, IssueType::LineTooLong);
+        assert_eq!(issue.line, Some(3));
+        assert_eq!(issue.column.unwrap().character, 11);
+        assert_eq!(issue.end_column.unwrap().character, 13);
+        assert_eq!(issue.message, "Line too long (12 > 10 columns)");
+
+        // Wide characters take two columns: five of them fill the line
+        assert!(checker.check_line_length("日本語の文", 1).is_none());
+        let issue = checker.check_line_length("日本語の文章", 1).unwrap();
+        assert_eq!(issue.column.unwrap().character, 6);
+        assert_eq!(issue.column.unwrap().byte, 16);
+    }
+
+    #[test]
+    fn test_check_line_length_expands_tabs() {
+        let mut checker = line_length_checker(8);
+        // A tab after two columns advances to column 4, then 4 more fit
+        assert!(checker.check_line_length("ab\tcdef", 1).is_none());
+        assert!(checker.check_line_length("ab\tcdefg", 1).is_some());
+
+        checker.config.checks.tab_width = 8;
+        assert!(checker.check_line_length("\tx", 1).is_some());
+        assert!(checker.check_line_length("ab\t", 1).is_none());
+    }
+
+    #[test]
+    fn test_check_line_length_exemptions() {
+        let mut checker = line_length_checker(20);
+        let url = "https://example.com/a/very/long/path";
+        assert!(checker.check_line_length(url, 1).is_none());
+        assert!(
+            checker
+                .check_line_length(&format!("  // {url}"), 1)
+                .is_none()
+        );
+        assert!(
+            checker
+                .check_line_length(&format!("- <{url}>"), 1)
+                .is_none()
+        );
+        assert!(
+            checker
+                .check_line_length(&format!("see {url}"), 1)
+                .is_some()
+        );
+
+        checker.config.checks.line_length_ignore_urls = false;
+        assert!(checker.check_line_length(url, 1).is_some());
+
+        checker.config.checks.line_length_ignore_pattern = Some("^use ".parse().unwrap());
+        assert!(
+            checker
+                .check_line_length("use crate::some::long::module::path;", 1)
+                .is_none()
+        );
+        assert!(
+            checker
+                .check_line_length("let value = some::long::module::path;", 1)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_check_content_line_length() {
+        assert!(
+            CheckerCore::new(Config::default())
+                .check_content_line_length(&"x".repeat(1000))
+                .is_empty()
+        );
+
+        let issues = line_length_checker(3).check_content("abc\nabcd\r\nab\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::LineTooLong);
+        assert_eq!(issues[0].line, Some(2));
+    }
+
+    fn indentation_checker(style: Option<IndentStyle>, mixed: bool) -> CheckerCore {
+        let mut config = Config::default();
+        config.checks.indent_style = style;
+        config.checks.mixed_indentation = mixed;
+        CheckerCore::new(config)
+    }
+
+    #[test]
+    fn test_check_indentation_style() {
+        let checker = indentation_checker(Some(IndentStyle::Spaces), false);
+        let issues = checker.check_content("fn main() {\n\tlet x = 1;\n    x\n}\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::TabIndentation);
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(issues[0].end_column.unwrap().character, 2);
+
+        let checker = indentation_checker(Some(IndentStyle::Tabs), false);
+        assert!(checker.check_line_indentation("\t  aligned", 1).is_none());
+        assert!(checker.check_line_indentation(" * comment", 1).is_none());
+        let issue = checker.check_line_indentation("    x", 1).unwrap();
+        assert_eq!(issue.issue_type, IssueType::SpaceIndentation);
+        assert!(checker.check_line_indentation(" \tx", 1).is_some());
+        // Alignment spaces must follow a tab
+        assert!(checker.check_line_indentation("  x", 1).is_some());
+
+        // Whitespace-only lines are left to the trailing space check
+        assert!(checker.check_line_indentation("        ", 1).is_none());
+    }
+
+    #[test]
+    fn test_check_mixed_indentation() {
+        let checker = indentation_checker(None, true);
+        let issues = checker.check_content("a\n\t b\n \tc\n\td\n    e\n");
+        let lines: Vec<_> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(2), Some(3)]);
+        assert!(
+            issues
+                .iter()
+                .all(|i| i.issue_type == IssueType::MixedIndentation)
+        );
+
+        // A mixed line is reported once, as mixed
+        let checker = indentation_checker(Some(IndentStyle::Spaces), true);
+        let issue = checker.check_line_indentation("\t x", 1).unwrap();
+        assert_eq!(issue.issue_type, IssueType::MixedIndentation);
+
+        assert!(
+            indentation_checker(None, false)
+                .check_content("\t x\n")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_check_unicode_hazards() {
+        let checker = CheckerCore::new(Config::default());
+        let issues = checker.check_content(
+            "ok\nif (admin) { /*\u{202E} } \u{2066}*/\nlet a\u{200B}b = 1;\nx\u{00A0}= 2;\n",
+        );
+        let found: Vec<_> = issues
+            .iter()
+            .map(|i| (i.issue_type, i.line, i.column.unwrap().character))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (IssueType::BidiControl, Some(2), 16),
+                (IssueType::BidiControl, Some(2), 20),
+                (IssueType::ZeroWidthCharacter, Some(3), 6),
+                (IssueType::NonBreakingSpace, Some(4), 2),
+            ]
+        );
+        assert_eq!(issues[0].message, "U+202E RIGHT-TO-LEFT OVERRIDE found");
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert_eq!(issues[2].severity, Severity::Warning);
+        assert_eq!(issues[3].end_column.unwrap().byte, 4);
+    }
+
+    #[test]
+    fn test_check_unicode_hazards_exemptions() {
+        let checker = CheckerCore::new(Config::default());
+        // Byte order mark, emoji ZWJ sequence and Persian ZWNJ
+        assert!(
+            checker
+                .check_content("\u{FEFF}text\n👩\u{200D}💻\nمی\u{200C}خواهم\n")
+                .is_empty()
+        );
+        // A U+FEFF anywhere else is a zero-width character
+        let issues = checker.check_content("a\n\u{FEFF}b\n");
+        assert_eq!(issues[0].issue_type, IssueType::ZeroWidthCharacter);
+
+        let mut config = Config::default();
+        config.checks.unicode_hazards = false;
+        assert!(
+            CheckerCore::new(config)
+                .check_content("\u{202E}\u{200B}\n")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_trailing_whitespace_names_unusual_characters() {
+        let checker = CheckerCore::new(Config::default());
+        let issue = checker
+            .check_line_trailing_whitespace("text\u{00A0} \u{3000}", 1)
+            .unwrap();
+        assert_eq!(
+            issue.message,
+            "Trailing spaces found (including U+00A0 NO-BREAK SPACE, U+3000 IDEOGRAPHIC SPACE)"
+        );
+    }
+
+    #[test]
+    fn test_check_bytes_legacy_encoding() {
+        let checker = CheckerCore::new(Config::default());
+        // Latin-1 "café" with trailing spaces, then Shift-JIS "日本" without final newline
+        let content = b"caf\xe9  \n\x93\xfa\x96\x7b";
+        let issues = checker.check_bytes(content);
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].issue_type, IssueType::TrailingSpace);
+        assert_eq!(issues[0].line, Some(1));
+        assert_eq!(issues[1].issue_type, IssueType::MissingNewline);
+    }
+
+    #[test]
+    fn test_check_bytes_require_utf8() {
+        let mut config = Config::default();
+        config.checks.require_utf8 = true;
+        let checker = CheckerCore::new(config);
+
+        let issues = checker.check_bytes(b"ok\nstill ok\ncaf\xe9\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::InvalidUtf8);
+        assert_eq!(issues[0].line, Some(3));
+        assert!(issues[0].message.contains("byte offset 15"));
+
+        assert!(checker.check_bytes("caf\u{e9}\n".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn test_is_binary_content() {
+        assert!(!is_binary_content(b""));
+        assert!(!is_binary_content(b"plain text\n\twith tabs\r\n"));
+        assert!(!is_binary_content(b"\x1b[31mcolored log\x1b[0m\n"));
+        assert!(!is_binary_content(b"caf\xe9 latin-1\n"));
+
+        // Any NUL byte is binary
+        assert!(is_binary_content(b"text\0more text"));
+
+        // Mostly control bytes is binary
+        assert!(is_binary_content(b"\x01\x02\x03\x04abc"));
+    }
+
+    #[test]
+    fn test_checker_core_config_getter() {
+        let config = Config::default();
+        let checker = CheckerCore::new(config.clone());
+
+        // Test the config() getter
+        assert_eq!(
+            checker.config().checks.trailing_spaces,
+            config.checks.trailing_spaces
+        );
+        assert_eq!(
             checker.config().checks.newline_ending,
             config.checks.newline_ending
         );
     }
+
+    fn bom_checker(policy: BomPolicy) -> CheckerCore {
+        let mut config = Config::default();
+        config.checks.bom = policy;
+        CheckerCore::new(config)
+    }
+
+    #[test]
+    fn test_check_bom() {
+        let with_bom = b"\xEF\xBB\xBFtext\n";
+
+        let issues = bom_checker(BomPolicy::Forbid).check_bytes(with_bom);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::UnexpectedBom);
+        assert_eq!(issues[0].line, None);
+        assert!(
+            bom_checker(BomPolicy::Forbid)
+                .check_bytes(b"text\n")
+                .is_empty()
+        );
+
+        let issues = bom_checker(BomPolicy::Require).check_bytes(b"text\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::MissingBom);
+        assert_eq!(issues[0].message, "Missing UTF-8 byte order mark");
+        assert!(
+            bom_checker(BomPolicy::Require)
+                .check_bytes(with_bom)
+                .is_empty()
+        );
+        assert!(bom_checker(BomPolicy::Require).check_bytes(b"").is_empty());
+
+        assert!(
+            bom_checker(BomPolicy::Ignore)
+                .check_bytes(with_bom)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_check_bytes_latin1_columns() {
+        let checker = CheckerCore::new(Config::default());
+        let issues = checker.check_bytes(b"caf\xe9 x  \n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::TrailingSpace);
+        // Columns of the raw bytes, not of the lossy decoding's U+FFFD
+        let column = issues[0].column.unwrap();
+        assert_eq!((column.character, column.byte), (7, 7));
+        assert_eq!(issues[0].end_column.unwrap().byte, 9);
+    }
+
+    #[test]
+    fn test_check_bom_utf16() {
+        // "a \n" in UTF-16LE, which would otherwise be reported as invalid UTF-8
+        let content = b"\xFF\xFEa\x00 \x00\n\x00";
+
+        let issues = bom_checker(BomPolicy::Forbid).check_bytes(content);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::Utf16Bom);
+        assert_eq!(
+            issues[0].message,
+            "UTF-16LE byte order mark found; file is not UTF-8"
+        );
+
+        let issues = bom_checker(BomPolicy::Require).check_bytes(b"\xFE\xFF\x00a");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::Utf16Bom);
+
+        // Reported under the default policy too, unless UTF-16 is expected
+        let issues = bom_checker(BomPolicy::Ignore).check_bytes(content);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::Utf16Bom);
+
+        let mut config = Config::default();
+        config.checks.allow_utf16 = true;
+        assert!(CheckerCore::new(config).check_bytes(content).is_empty());
+    }
+
+    #[test]
+    fn test_check_conflict_markers() {
+        let checker = CheckerCore::new(Config::default());
+        let content = "a\n<<<<<<< HEAD\nb\n||||||| base\n=======\nc\n>>>>>>> feature\n";
+        let issues = checker.check_content(content);
+        let lines: Vec<_> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(2), Some(4), Some(5), Some(7)]);
+        assert!(
+            issues
+                .iter()
+                .all(|i| i.issue_type == IssueType::ConflictMarker)
+        );
+        assert_eq!(issues[0].message, "Merge conflict marker found (<<<<<<<)");
+        assert_eq!(issues[0].column.map(|c| c.byte), Some(1));
+        assert_eq!(issues[0].end_column.map(|c| c.byte), Some(8));
+
+        // A byte order mark does not hide a marker on the first line
+        let issues = checker.check_bytes(b"\xEF\xBB\xBF<<<<<<< HEAD\na\n=======\n");
+        let markers: Vec<_> = issues
+            .iter()
+            .filter(|i| i.issue_type == IssueType::ConflictMarker)
+            .map(|i| (i.line, i.column.map(|c| (c.character, c.byte))))
+            .collect();
+        assert_eq!(
+            markers,
+            vec![(Some(1), Some((2, 4))), (Some(3), Some((1, 1)))]
+        );
+    }
+
+    #[test]
+    fn test_check_conflict_markers_exact_width() {
+        let checker = CheckerCore::new(Config::default());
+        // Setext headings, reStructuredText adornments and markers not at the
+        // start of a line are left alone
+        let content = "Title\n========\n\nSection\n>>>>>>>>\n<<<<<<<<\n ======= \n\
+            ======= x\n<<<<<<<HEAD\n";
+        assert!(checker.check_content_conflict_markers(content).is_empty());
+
+        // Separators outside a conflict block, as under a seven-character
+        // setext heading, are not markers either
+        let content = "Example\n=======\n\nBase\n|||||||\n";
+        assert!(checker.check_content_conflict_markers(content).is_empty());
+        let issues =
+            checker.check_content_conflict_markers("<<<<<<< a\n=======\n>>>>>>> b\n=======\n");
+        let lines: Vec<_> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(1), Some(2), Some(3)]);
+
+        let mut config = Config::default();
+        config.checks.conflict_markers = false;
+        let checker = CheckerCore::new(config);
+        assert!(
+            checker
+                .check_content_conflict_markers("<<<<<<< HEAD\n")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_check_consecutive_blank_lines() {
+        let mut config = Config::default();
+        config.checks.max_consecutive_blank_lines = Some(1);
+        let checker = CheckerCore::new(config);
+
+        // Whitespace-only lines are blank too
+        let issues = checker.check_content_blank_lines("a\n\nb\n\n \n\t\nc\n");
+        assert_eq!(issues.len(), 1);
+        let issue = &issues[0];
+        assert_eq!(issue.issue_type, IssueType::ConsecutiveBlankLines);
+        assert_eq!((issue.line, issue.end_line), (Some(4), Some(6)));
+        assert_eq!(issue.message, "3 consecutive blank lines (max 1)");
+
+        // Blank lines at the end of the file are the newline ending check's
+        let issues = checker.check_content_blank_lines("a\n\n\n\n");
+        assert!(issues.is_empty());
+
+        // Including a first line holding only a byte order mark
+        let issues = checker.check_bytes(b"\xEF\xBB\xBF\n\n\tfoo\n");
+        assert!(
+            issues
+                .iter()
+                .any(|i| i.issue_type == IssueType::ConsecutiveBlankLines
+                    && (i.line, i.end_line) == (Some(1), Some(2)))
+        );
+    }
+
+    #[test]
+    fn test_check_leading_blank_lines() {
+        let mut config = Config::default();
+        config.checks.leading_blank_lines = true;
+        let checker = CheckerCore::new(config);
+
+        let issues = checker.check_content_blank_lines("\n\r\na\n\n\nb\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::LeadingBlankLines);
+        assert_eq!((issues[0].line, issues[0].end_line), (Some(1), Some(2)));
+        assert_eq!(issues[0].message, "2 blank lines at start of file");
+
+        let issues = checker.check_content_blank_lines("\na\n");
+        assert_eq!((issues[0].line, issues[0].end_line), (Some(1), None));
+        assert_eq!(issues[0].message, "Blank line at start of file");
+
+        assert!(checker.check_content_blank_lines("a\n").is_empty());
+        assert!(checker.check_content_blank_lines("\n\n").is_empty());
+
+        // A byte order mark alone does not make the first line content
+        let issues = checker.check_content_blank_lines("\u{FEFF}\n\n\tfoo\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::LeadingBlankLines);
+        assert_eq!((issues[0].line, issues[0].end_line), (Some(1), Some(2)));
+        assert!(
+            checker
+                .check_content_blank_lines("a\n\u{FEFF}\nb\n")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_whitespace_only_lines_are_a_separate_rule() {
+        let content = "code  \n   \n\t\n";
+        let types = |checker: &CheckerCore| -> Vec<_> {
+            checker
+                .check_content_trailing_whitespace(content)
+                .iter()
+                .map(|i| (i.issue_type, i.line))
+                .collect()
+        };
+
+        let checker = CheckerCore::new(Config::default());
+        assert_eq!(
+            types(&checker),
+            vec![
+                (IssueType::TrailingSpace, Some(1)),
+                (IssueType::WhitespaceOnlyLine, Some(2)),
+                (IssueType::WhitespaceOnlyLine, Some(3)),
+            ]
+        );
+        assert_eq!(
+            checker.check_content("   \n")[0].message,
+            "Whitespace-only line found"
+        );
+
+        let mut config = Config::default();
+        config.checks.whitespace_only_lines = Some(false);
+        let checker = CheckerCore::new(config);
+        assert_eq!(types(&checker), vec![(IssueType::TrailingSpace, Some(1))]);
+
+        // Unless set, whitespace-only lines follow `trailing_spaces`
+        let mut config = Config::default();
+        config.checks.trailing_spaces = false;
+        assert!(types(&CheckerCore::new(config.clone())).is_empty());
+
+        config.checks.whitespace_only_lines = Some(true);
+        let checker = CheckerCore::new(config);
+        assert_eq!(
+            types(&checker),
+            vec![
+                (IssueType::WhitespaceOnlyLine, Some(2)),
+                (IssueType::WhitespaceOnlyLine, Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markdown_hard_breaks() {
+        let mut config = Config::default();
+        config.checks.markdown_hard_breaks = true;
+        let checker = CheckerCore::new(config);
+
+        let issues =
+            checker.check_content_trailing_whitespace("one \ntwo  \nthree   \ntab\t \n  \n");
+        let lines: Vec<_> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(1), Some(3), Some(4), Some(5)]);
+        assert_eq!(issues[3].issue_type, IssueType::WhitespaceOnlyLine);
+    }
+
+    #[test]
+    fn test_check_control_characters() {
+        let checker = CheckerCore::new(Config::default());
+        let issues = checker.check_content("\tok\nred: \x1b[31mx\x1b[0m\nc\rd\r\n\x0c\n");
+        let found: Vec<_> = issues
+            .iter()
+            .filter(|i| i.issue_type == IssueType::ControlCharacter)
+            .map(|i| (i.line, i.column.map(|c| c.character), i.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), Some(6), "U+001B ESCAPE found (2 occurrences)"),
+                (Some(3), Some(2), "U+000D CARRIAGE RETURN found"),
+            ]
+        );
+        // Form feeds are allowed by default, and are not whitespace either
+        assert!(checker.check_content("\x0c\na\x0c\n").is_empty());
+
+        // Carriage returns left to the line ending check
+        let mut config = Config::default();
+        config.checks.line_ending = Some(LineEndingStyle::Lf);
+        let checker = CheckerCore::new(config.clone());
+        assert!(
+            checker
+                .check_content_control_characters("c\rd\n")
+                .is_empty()
+        );
+
+        config.checks.allowed_control_characters = vec!['\x07'];
+        let checker = CheckerCore::new(config);
+        let issues = checker.check_content_control_characters("\x07\x0c\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].message, "U+000C FORM FEED found");
+
+        // Distinct characters on a line are named once each
+        let issues = checker.check_content_control_characters("\x1b\x0c\x1b\x00\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "U+001B ESCAPE, U+000C FORM FEED, U+0000 NULL found (4 occurrences)"
+        );
+    }
 }
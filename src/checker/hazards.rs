@@ -0,0 +1,342 @@
+//! Unicode hazards: characters that are invisible or change how text renders
+//!
+//! Bidirectional controls can make code display in a different order than it
+//! is compiled ("Trojan Source"), and zero-width or non-breaking spaces look
+//! like nothing or like an ordinary space. Stray control characters, such as
+//! escape sequences pasted from a terminal, are similarly invisible. Each
+//! hazard is reported with its code point and name so it can be found in an
+//! editor.
+
+use crate::IssueType;
+
+/// Hazardous characters, the rule reporting each and its Unicode name
+const HAZARDS: &[(char, IssueType, &str)] = &[
+    ('\u{00A0}', IssueType::NonBreakingSpace, "NO-BREAK SPACE"),
+    ('\u{00AD}', IssueType::InvisibleCharacter, "SOFT HYPHEN"),
+    (
+        '\u{034F}',
+        IssueType::InvisibleCharacter,
+        "COMBINING GRAPHEME JOINER",
+    ),
+    (
+        '\u{061C}',
+        IssueType::InvisibleCharacter,
+        "ARABIC LETTER MARK",
+    ),
+    (
+        '\u{115F}',
+        IssueType::InvisibleCharacter,
+        "HANGUL CHOSEONG FILLER",
+    ),
+    (
+        '\u{1160}',
+        IssueType::InvisibleCharacter,
+        "HANGUL JUNGSEONG FILLER",
+    ),
+    (
+        '\u{17B4}',
+        IssueType::InvisibleCharacter,
+        "KHMER VOWEL INHERENT AQ",
+    ),
+    (
+        '\u{17B5}',
+        IssueType::InvisibleCharacter,
+        "KHMER VOWEL INHERENT AA",
+    ),
+    (
+        '\u{180E}',
+        IssueType::InvisibleCharacter,
+        "MONGOLIAN VOWEL SEPARATOR",
+    ),
+    ('\u{2007}', IssueType::NonBreakingSpace, "FIGURE SPACE"),
+    (
+        '\u{200B}',
+        IssueType::ZeroWidthCharacter,
+        "ZERO WIDTH SPACE",
+    ),
+    (
+        '\u{200C}',
+        IssueType::ZeroWidthCharacter,
+        "ZERO WIDTH NON-JOINER",
+    ),
+    (
+        '\u{200D}',
+        IssueType::ZeroWidthCharacter,
+        "ZERO WIDTH JOINER",
+    ),
+    (
+        '\u{200E}',
+        IssueType::InvisibleCharacter,
+        "LEFT-TO-RIGHT MARK",
+    ),
+    (
+        '\u{200F}',
+        IssueType::InvisibleCharacter,
+        "RIGHT-TO-LEFT MARK",
+    ),
+    (
+        '\u{202A}',
+        IssueType::BidiControl,
+        "LEFT-TO-RIGHT EMBEDDING",
+    ),
+    (
+        '\u{202B}',
+        IssueType::BidiControl,
+        "RIGHT-TO-LEFT EMBEDDING",
+    ),
+    (
+        '\u{202C}',
+        IssueType::BidiControl,
+        "POP DIRECTIONAL FORMATTING",
+    ),
+    ('\u{202D}', IssueType::BidiControl, "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202E}', IssueType::BidiControl, "RIGHT-TO-LEFT OVERRIDE"),
+    (
+        '\u{202F}',
+        IssueType::NonBreakingSpace,
+        "NARROW NO-BREAK SPACE",
+    ),
+    ('\u{2060}', IssueType::ZeroWidthCharacter, "WORD JOINER"),
+    (
+        '\u{2061}',
+        IssueType::InvisibleCharacter,
+        "FUNCTION APPLICATION",
+    ),
+    ('\u{2062}', IssueType::InvisibleCharacter, "INVISIBLE TIMES"),
+    (
+        '\u{2063}',
+        IssueType::InvisibleCharacter,
+        "INVISIBLE SEPARATOR",
+    ),
+    ('\u{2064}', IssueType::InvisibleCharacter, "INVISIBLE PLUS"),
+    ('\u{2066}', IssueType::BidiControl, "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', IssueType::BidiControl, "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', IssueType::BidiControl, "FIRST STRONG ISOLATE"),
+    (
+        '\u{2069}',
+        IssueType::BidiControl,
+        "POP DIRECTIONAL ISOLATE",
+    ),
+    (
+        '\u{206A}',
+        IssueType::InvisibleCharacter,
+        "INHIBIT SYMMETRIC SWAPPING",
+    ),
+    (
+        '\u{206B}',
+        IssueType::InvisibleCharacter,
+        "ACTIVATE SYMMETRIC SWAPPING",
+    ),
+    (
+        '\u{206C}',
+        IssueType::InvisibleCharacter,
+        "INHIBIT ARABIC FORM SHAPING",
+    ),
+    (
+        '\u{206D}',
+        IssueType::InvisibleCharacter,
+        "ACTIVATE ARABIC FORM SHAPING",
+    ),
+    (
+        '\u{206E}',
+        IssueType::InvisibleCharacter,
+        "NATIONAL DIGIT SHAPES",
+    ),
+    (
+        '\u{206F}',
+        IssueType::InvisibleCharacter,
+        "NOMINAL DIGIT SHAPES",
+    ),
+    ('\u{3164}', IssueType::InvisibleCharacter, "HANGUL FILLER"),
+    (
+        '\u{FEFF}',
+        IssueType::ZeroWidthCharacter,
+        "ZERO WIDTH NO-BREAK SPACE",
+    ),
+    (
+        '\u{FFA0}',
+        IssueType::InvisibleCharacter,
+        "HALFWIDTH HANGUL FILLER",
+    ),
+    (
+        '\u{FFF9}',
+        IssueType::InvisibleCharacter,
+        "INTERLINEAR ANNOTATION ANCHOR",
+    ),
+    (
+        '\u{FFFA}',
+        IssueType::InvisibleCharacter,
+        "INTERLINEAR ANNOTATION SEPARATOR",
+    ),
+    (
+        '\u{FFFB}',
+        IssueType::InvisibleCharacter,
+        "INTERLINEAR ANNOTATION TERMINATOR",
+    ),
+];
+
+/// Names of other whitespace characters, used when describing trailing
+/// whitespace
+const WHITESPACE_NAMES: &[(char, &str)] = &[
+    ('\u{0085}', "NEXT LINE"),
+    ('\u{1680}', "OGHAM SPACE MARK"),
+    ('\u{2000}', "EN QUAD"),
+    ('\u{2001}', "EM QUAD"),
+    ('\u{2002}', "EN SPACE"),
+    ('\u{2003}', "EM SPACE"),
+    ('\u{2004}', "THREE-PER-EM SPACE"),
+    ('\u{2005}', "FOUR-PER-EM SPACE"),
+    ('\u{2006}', "SIX-PER-EM SPACE"),
+    ('\u{2008}', "PUNCTUATION SPACE"),
+    ('\u{2009}', "THIN SPACE"),
+    ('\u{200A}', "HAIR SPACE"),
+    ('\u{2028}', "LINE SEPARATOR"),
+    ('\u{2029}', "PARAGRAPH SEPARATOR"),
+    ('\u{205F}', "MEDIUM MATHEMATICAL SPACE"),
+    ('\u{3000}', "IDEOGRAPHIC SPACE"),
+];
+
+/// Names of the C0 control characters, indexed by code point
+const C0_NAMES: [&str; 32] = [
+    "NULL",
+    "START OF HEADING",
+    "START OF TEXT",
+    "END OF TEXT",
+    "END OF TRANSMISSION",
+    "ENQUIRY",
+    "ACKNOWLEDGE",
+    "ALERT",
+    "BACKSPACE",
+    "CHARACTER TABULATION",
+    "LINE FEED",
+    "LINE TABULATION",
+    "FORM FEED",
+    "CARRIAGE RETURN",
+    "SHIFT OUT",
+    "SHIFT IN",
+    "DATA LINK ESCAPE",
+    "DEVICE CONTROL ONE",
+    "DEVICE CONTROL TWO",
+    "DEVICE CONTROL THREE",
+    "DEVICE CONTROL FOUR",
+    "NEGATIVE ACKNOWLEDGE",
+    "SYNCHRONOUS IDLE",
+    "END OF TRANSMISSION BLOCK",
+    "CANCEL",
+    "END OF MEDIUM",
+    "SUBSTITUTE",
+    "ESCAPE",
+    "INFORMATION SEPARATOR FOUR",
+    "INFORMATION SEPARATOR THREE",
+    "INFORMATION SEPARATOR TWO",
+    "INFORMATION SEPARATOR ONE",
+];
+
+/// Whether `c` is a control character to report: any C0 or C1 control or DEL
+/// other than tab, line feed and those in `allowed`
+///
+/// Carriage returns are left to the line ending check when `line_ending` is
+/// set, so `report_cr` is false then.
+pub fn is_stray_control(c: char, allowed: &[char], report_cr: bool) -> bool {
+    c.is_control() && !matches!(c, '\t' | '\n') && (c != '\r' || report_cr) && !allowed.contains(&c)
+}
+
+/// The rule reporting `c` and its name, if `c` is a hazard
+pub fn classify(c: char) -> Option<(IssueType, &'static str)> {
+    if let Ok(index) = HAZARDS.binary_search_by_key(&c, |&(hazard, _, _)| hazard) {
+        let (_, issue_type, name) = HAZARDS[index];
+        return Some((issue_type, name));
+    }
+
+    // Tag characters are invisible and have been used to smuggle text
+    match c {
+        '\u{E0001}' => Some((IssueType::InvisibleCharacter, "LANGUAGE TAG")),
+        '\u{E0020}'..='\u{E007F}' => Some((IssueType::InvisibleCharacter, "TAG CHARACTER")),
+        _ => None,
+    }
+}
+
+/// `U+XXXX` followed by the character's name when it is known
+pub fn describe(c: char) -> String {
+    let name = classify(c)
+        .map(|(_, name)| name)
+        .or_else(|| {
+            WHITESPACE_NAMES
+                .iter()
+                .find(|&&(whitespace, _)| whitespace == c)
+                .map(|&(_, name)| name)
+        })
+        .or_else(|| C0_NAMES.get(u32::from(c) as usize).copied())
+        .or((c == '\u{7F}').then_some("DELETE"));
+
+    match name {
+        Some(name) => format!("U+{:04X} {name}", u32::from(c)),
+        None => format!("U+{:04X}", u32::from(c)),
+    }
+}
+
+/// Whether the joiner at `byte` in `line` joins two non-ASCII characters, as in
+/// emoji sequences and scripts such as Persian, rather than hiding in ASCII text
+pub fn is_joining(line: &str, byte: usize, c: char) -> bool {
+    let before = line[..byte].chars().next_back();
+    let after = line[byte + c.len_utf8()..].chars().next();
+    matches!((before, after), (Some(b), Some(a)) if !b.is_ascii() && !a.is_ascii())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hazard_table_is_sorted() {
+        assert!(HAZARDS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_classify_and_describe() {
+        assert_eq!(
+            classify('\u{202E}'),
+            Some((IssueType::BidiControl, "RIGHT-TO-LEFT OVERRIDE"))
+        );
+        assert_eq!(
+            classify('\u{00A0}').map(|(t, _)| t),
+            Some(IssueType::NonBreakingSpace)
+        );
+        assert_eq!(
+            classify('\u{E0041}').map(|(t, _)| t),
+            Some(IssueType::InvisibleCharacter)
+        );
+        assert_eq!(classify('a'), None);
+        assert_eq!(classify(' '), None);
+
+        assert_eq!(describe('\u{200B}'), "U+200B ZERO WIDTH SPACE");
+        assert_eq!(describe('\u{3000}'), "U+3000 IDEOGRAPHIC SPACE");
+        assert_eq!(describe('\u{E0041}'), "U+E0041 TAG CHARACTER");
+        assert_eq!(describe('é'), "U+00E9");
+        assert_eq!(describe('\u{1B}'), "U+001B ESCAPE");
+        assert_eq!(describe('\u{7F}'), "U+007F DELETE");
+        assert_eq!(describe('\u{9B}'), "U+009B");
+    }
+
+    #[test]
+    fn test_is_stray_control() {
+        assert!(is_stray_control('\u{1B}', &[], true));
+        assert!(is_stray_control('\u{9B}', &[], true));
+        assert!(is_stray_control('\u{7F}', &[], true));
+        assert!(!is_stray_control('\t', &[], true));
+        assert!(!is_stray_control('\n', &[], true));
+        assert!(!is_stray_control('a', &[], true));
+        assert!(!is_stray_control('\u{0C}', &['\u{0C}'], true));
+        assert!(is_stray_control('\r', &[], true));
+        assert!(!is_stray_control('\r', &[], false));
+    }
+
+    #[test]
+    fn test_is_joining() {
+        let emoji = "👩\u{200D}💻";
+        assert!(is_joining(emoji, 4, '\u{200D}'));
+        let code = "ab\u{200D}cd";
+        assert!(!is_joining(code, 2, '\u{200D}'));
+        assert!(!is_joining("\u{200C}", 0, '\u{200C}'));
+    }
+}
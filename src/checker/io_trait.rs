@@ -19,9 +19,31 @@ pub trait FileReader: Send + Sync {
     /// Read the entire contents of a file into a string
     fn read_to_string(&self, path: &Path) -> io::Result<String>;
 
+    /// Read the entire contents of a file as raw bytes
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
     /// Open a file for reading
     fn open(&self, path: &Path) -> io::Result<Box<dyn io::Read>>;
 
     /// Get metadata about a file
     fn metadata(&self, path: &Path) -> io::Result<FileMetadata>;
 }
+
+/// Readers can be shared, e.g. between a checker and a later re-read
+impl<R: FileReader + ?Sized> FileReader for &R {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        (**self).read_to_string(path)
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        (**self).read(path)
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn io::Read>> {
+        (**self).open(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        (**self).metadata(path)
+    }
+}
@@ -1,18 +1,27 @@
 pub mod core;
 pub mod file_checker;
+pub mod hazards;
 pub mod io_trait;
+pub mod paths;
+pub mod suppression;
 pub mod traits;
 
 #[cfg(test)]
 mod tests;
 
 use crate::config::Config;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 // Re-export traits and core
-pub use core::CheckerCore;
+pub use core::{
+    BINARY_SNIFF_LEN, CheckerCore, LineEnding, UTF8_BOM, is_binary_content, restore_byte_columns,
+    utf16_bom,
+};
 pub use file_checker::{FileChecker, StdFileReader};
 pub use io_trait::{FileMetadata, FileReader};
+pub use suppression::Suppressions;
 pub use traits::{ContentChecker, LineChecker};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -26,14 +35,309 @@ pub struct CheckResult {
 pub struct Issue {
     pub issue_type: IssueType,
     pub line: Option<usize>,
+    /// Last line of a multi-line issue, if it spans more than `line`
+    pub end_line: Option<usize>,
+    /// First column of the offending span, if the issue has one
+    pub column: Option<Column>,
+    /// Column just past the end of the offending span
+    pub end_column: Option<Column>,
+    /// How serious the issue is, as configured for its rule
+    pub severity: Severity,
     pub message: String,
 }
 
+/// A 1-based position within a line, as both a character and a byte offset
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Column {
+    pub character: usize,
+    pub byte: usize,
+}
+
+impl Column {
+    /// Column of byte offset `byte` (0-based, on a char boundary) in `line`
+    pub fn at(line: &str, byte: usize) -> Self {
+        Self {
+            character: line[..byte].chars().count() + 1,
+            byte: byte + 1,
+        }
+    }
+}
+
+/// How serious an issue is, from least to most severe
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Lowercase name used in output
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IssueType {
     MissingNewline,
     MultipleNewlines,
     TrailingSpace,
+    CrlfLineEnding,
+    LfLineEnding,
+    LoneCarriageReturn,
+    MixedLineEndings,
+    InvalidUtf8,
+    UnusedSuppression,
+    LineTooLong,
+    TabIndentation,
+    SpaceIndentation,
+    MixedIndentation,
+    BidiControl,
+    ZeroWidthCharacter,
+    NonBreakingSpace,
+    InvisibleCharacter,
+    UnexpectedBom,
+    MissingBom,
+    Utf16Bom,
+    ConflictMarker,
+    ConsecutiveBlankLines,
+    LeadingBlankLines,
+    WhitespaceOnlyLine,
+    ControlCharacter,
+    ReservedFilename,
+    FilenameTrailingDotOrSpace,
+    InvalidFilenameCharacter,
+    FilenameCaseCollision,
+    NonNfcFilename,
+}
+
+impl IssueType {
+    /// Every issue type, in the order rules are listed in reports
+    pub const ALL: &[IssueType] = &[
+        IssueType::MissingNewline,
+        IssueType::MultipleNewlines,
+        IssueType::TrailingSpace,
+        IssueType::CrlfLineEnding,
+        IssueType::LfLineEnding,
+        IssueType::LoneCarriageReturn,
+        IssueType::MixedLineEndings,
+        IssueType::InvalidUtf8,
+        IssueType::UnusedSuppression,
+        IssueType::LineTooLong,
+        IssueType::TabIndentation,
+        IssueType::SpaceIndentation,
+        IssueType::MixedIndentation,
+        IssueType::BidiControl,
+        IssueType::ZeroWidthCharacter,
+        IssueType::NonBreakingSpace,
+        IssueType::InvisibleCharacter,
+        IssueType::UnexpectedBom,
+        IssueType::MissingBom,
+        IssueType::Utf16Bom,
+        IssueType::ConflictMarker,
+        IssueType::ConsecutiveBlankLines,
+        IssueType::LeadingBlankLines,
+        IssueType::WhitespaceOnlyLine,
+        IssueType::ControlCharacter,
+        IssueType::ReservedFilename,
+        IssueType::FilenameTrailingDotOrSpace,
+        IssueType::InvalidFilenameCharacter,
+        IssueType::FilenameCaseCollision,
+        IssueType::NonNfcFilename,
+    ];
+
+    /// Stable rule code, never reused or renumbered
+    pub fn code(self) -> &'static str {
+        match self {
+            IssueType::MissingNewline => "LG001",
+            IssueType::MultipleNewlines => "LG002",
+            IssueType::TrailingSpace => "LG003",
+            IssueType::CrlfLineEnding => "LG004",
+            IssueType::LfLineEnding => "LG005",
+            IssueType::LoneCarriageReturn => "LG006",
+            IssueType::MixedLineEndings => "LG007",
+            IssueType::InvalidUtf8 => "LG008",
+            IssueType::UnusedSuppression => "LG009",
+            IssueType::LineTooLong => "LG010",
+            IssueType::TabIndentation => "LG011",
+            IssueType::SpaceIndentation => "LG012",
+            IssueType::MixedIndentation => "LG013",
+            IssueType::BidiControl => "LG014",
+            IssueType::ZeroWidthCharacter => "LG015",
+            IssueType::NonBreakingSpace => "LG016",
+            IssueType::InvisibleCharacter => "LG017",
+            IssueType::UnexpectedBom => "LG018",
+            IssueType::MissingBom => "LG019",
+            IssueType::Utf16Bom => "LG020",
+            IssueType::ConflictMarker => "LG021",
+            IssueType::ConsecutiveBlankLines => "LG022",
+            IssueType::LeadingBlankLines => "LG023",
+            IssueType::WhitespaceOnlyLine => "LG024",
+            IssueType::ControlCharacter => "LG025",
+            IssueType::ReservedFilename => "LG026",
+            IssueType::FilenameTrailingDotOrSpace => "LG027",
+            IssueType::InvalidFilenameCharacter => "LG028",
+            IssueType::FilenameCaseCollision => "LG029",
+            IssueType::NonNfcFilename => "LG030",
+        }
+    }
+
+    /// Severity of the rule unless configured otherwise
+    pub fn default_severity(self) -> Severity {
+        match self {
+            // Introduced as warnings, as they are on by default; bidi controls
+            // stay errors, since they can hide code ("Trojan Source")
+            IssueType::ZeroWidthCharacter
+            | IssueType::NonBreakingSpace
+            | IssueType::InvisibleCharacter => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// The rule this one was split out of, whose suppression comments and
+    /// baseline entries still cover it
+    pub fn split_from(self) -> Option<IssueType> {
+        match self {
+            IssueType::WhitespaceOnlyLine => Some(IssueType::TrailingSpace),
+            _ => None,
+        }
+    }
+
+    /// Machine-readable name used in JSON and SARIF output
+    pub fn name(self) -> &'static str {
+        match self {
+            IssueType::MissingNewline => "missing_newline",
+            IssueType::MultipleNewlines => "multiple_newlines",
+            IssueType::TrailingSpace => "trailing_space",
+            IssueType::CrlfLineEnding => "crlf_line_ending",
+            IssueType::LfLineEnding => "lf_line_ending",
+            IssueType::LoneCarriageReturn => "lone_carriage_return",
+            IssueType::MixedLineEndings => "mixed_line_endings",
+            IssueType::InvalidUtf8 => "invalid_utf8",
+            IssueType::UnusedSuppression => "unused_suppression",
+            IssueType::LineTooLong => "line_too_long",
+            IssueType::TabIndentation => "tab_indentation",
+            IssueType::SpaceIndentation => "space_indentation",
+            IssueType::MixedIndentation => "mixed_indentation",
+            IssueType::BidiControl => "bidi_control",
+            IssueType::ZeroWidthCharacter => "zero_width_character",
+            IssueType::NonBreakingSpace => "non_breaking_space",
+            IssueType::InvisibleCharacter => "invisible_character",
+            IssueType::UnexpectedBom => "unexpected_bom",
+            IssueType::MissingBom => "missing_bom",
+            IssueType::Utf16Bom => "utf16_bom",
+            IssueType::ConflictMarker => "conflict_marker",
+            IssueType::ConsecutiveBlankLines => "consecutive_blank_lines",
+            IssueType::LeadingBlankLines => "leading_blank_lines",
+            IssueType::WhitespaceOnlyLine => "whitespace_only_line",
+            IssueType::ControlCharacter => "control_character",
+            IssueType::ReservedFilename => "reserved_filename",
+            IssueType::FilenameTrailingDotOrSpace => "filename_trailing_dot_or_space",
+            IssueType::InvalidFilenameCharacter => "invalid_filename_character",
+            IssueType::FilenameCaseCollision => "filename_case_collision",
+            IssueType::NonNfcFilename => "non_nfc_filename",
+        }
+    }
+
+    /// Look up an issue type by code or name, accepting `-` in place of `_`
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.replace('-', "_");
+        IssueType::ALL
+            .iter()
+            .copied()
+            .find(|t| t.name() == name || t.code().eq_ignore_ascii_case(&name))
+    }
+
+    /// One-line description of what the check reports
+    pub fn description(self) -> &'static str {
+        match self {
+            IssueType::MissingNewline => "File does not end with a newline",
+            IssueType::MultipleNewlines => "File ends with more than one newline",
+            IssueType::TrailingSpace => "Line has trailing whitespace",
+            IssueType::CrlfLineEnding => "Line ends with CRLF where LF is expected",
+            IssueType::LfLineEnding => "Line ends with LF where CRLF is expected",
+            IssueType::LoneCarriageReturn => "Line contains a carriage return without a line feed",
+            IssueType::MixedLineEndings => "File mixes LF and CRLF line endings",
+            IssueType::InvalidUtf8 => "File is not valid UTF-8",
+            IssueType::UnusedSuppression => "Suppression comment does not suppress any issue",
+            IssueType::LineTooLong => "Line is wider than `max_line_length`",
+            IssueType::TabIndentation => "Line is indented with tabs where spaces are expected",
+            IssueType::SpaceIndentation => "Line is indented with spaces where tabs are expected",
+            IssueType::MixedIndentation => "Line indentation mixes tabs and spaces",
+            IssueType::BidiControl => "Line contains a Unicode bidirectional control character",
+            IssueType::ZeroWidthCharacter => "Line contains a zero-width character",
+            IssueType::NonBreakingSpace => "Line contains a non-breaking space",
+            IssueType::InvisibleCharacter => "Line contains an invisible formatting character",
+            IssueType::UnexpectedBom => "File starts with a UTF-8 byte order mark",
+            IssueType::MissingBom => "File does not start with a UTF-8 byte order mark",
+            IssueType::Utf16Bom => "File starts with a UTF-16 byte order mark",
+            IssueType::ConflictMarker => "Line is a merge conflict marker",
+            IssueType::ConsecutiveBlankLines => "Too many consecutive blank lines",
+            IssueType::LeadingBlankLines => "File starts with blank lines",
+            IssueType::WhitespaceOnlyLine => "Line contains only whitespace",
+            IssueType::ControlCharacter => "Line contains a control character",
+            IssueType::ReservedFilename => "Path uses a name Windows reserves",
+            IssueType::FilenameTrailingDotOrSpace => "Path has a name ending with a dot or space",
+            IssueType::InvalidFilenameCharacter => {
+                "Path has a name with a character Windows does not allow"
+            },
+            IssueType::FilenameCaseCollision => "Path differs from another only in case",
+            IssueType::NonNfcFilename => "Path has a name not in Unicode NFC",
+        }
+    }
+
+    /// How to resolve the issue
+    pub fn help(self) -> &'static str {
+        match self {
+            IssueType::MissingNewline => "Add a single newline at the end of the file.",
+            IssueType::MultipleNewlines => "Remove the extra blank lines at the end of the file.",
+            IssueType::TrailingSpace => "Remove spaces and tabs at the end of the line.",
+            IssueType::CrlfLineEnding | IssueType::LfLineEnding => {
+                "Convert line endings to the style configured by `line_ending`."
+            },
+            IssueType::LoneCarriageReturn => "Replace the carriage return with a line ending.",
+            IssueType::MixedLineEndings => "Use the same line ending throughout the file.",
+            IssueType::InvalidUtf8 => "Re-encode the file as UTF-8.",
+            IssueType::UnusedSuppression => {
+                "Remove the suppression comment or correct the rule names it lists."
+            },
+            IssueType::LineTooLong => "Break the line up or shorten it.",
+            IssueType::TabIndentation | IssueType::SpaceIndentation => {
+                "Re-indent the line in the style configured by `indent_style`."
+            },
+            IssueType::MixedIndentation => "Indent the line with only tabs or only spaces.",
+            IssueType::BidiControl => {
+                "Remove the character; if it is intended, escape it in a string literal."
+            },
+            IssueType::ZeroWidthCharacter | IssueType::InvisibleCharacter => {
+                "Remove the character, or escape it if it is intended."
+            },
+            IssueType::NonBreakingSpace => "Replace the character with an ordinary space.",
+            IssueType::UnexpectedBom => "Remove the byte order mark.",
+            IssueType::MissingBom => "Add a UTF-8 byte order mark at the start of the file.",
+            IssueType::Utf16Bom => "Re-encode the file as UTF-8.",
+            IssueType::ConflictMarker => "Resolve the merge conflict and remove the markers.",
+            IssueType::ConsecutiveBlankLines => "Remove the extra blank lines.",
+            IssueType::LeadingBlankLines => "Remove the blank lines at the start of the file.",
+            IssueType::WhitespaceOnlyLine => "Remove the whitespace, leaving an empty line.",
+            IssueType::ControlCharacter => {
+                "Remove the character, or list it in `allowed_control_characters`."
+            },
+            IssueType::ReservedFilename
+            | IssueType::FilenameTrailingDotOrSpace
+            | IssueType::InvalidFilenameCharacter => "Rename the file or directory.",
+            IssueType::FilenameCaseCollision => "Rename one of the paths or merge them.",
+            IssueType::NonNfcFilename => "Rename the file using NFC-normalized characters.",
+        }
+    }
 }
 
 pub fn check_file(path: &Path, config: &Config) -> CheckResult {
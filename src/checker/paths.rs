@@ -0,0 +1,341 @@
+//! Path-level checks for file names that are not portable
+//!
+//! These run once over every discovered path rather than over file content:
+//! names Windows reserves or cannot store, names macOS would normalise or
+//! merge, and paths that collide on case-insensitive file systems.
+
+use crate::checker::{CheckerCore, Suppressions};
+use crate::config::Config;
+use crate::{Issue, IssueType, Severity};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use unicode_normalization::{UnicodeNormalization, is_nfc};
+
+/// Device names Windows reserves, with or without an extension
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Characters Windows does not allow in file names; `:` is also the path
+/// separator of classic macOS APIs
+const INVALID_CHARACTERS: &[char] = &['<', '>', ':', '"', '|', '?', '*', '\\'];
+
+/// Check every path in `files` for non-portable names
+///
+/// Relative paths have every component checked; absolute paths only their
+/// file name, as their other components lie outside the checked tree. Issues
+/// are keyed by path and have no line.
+pub fn check_paths(files: &[PathBuf], config: &Config) -> HashMap<PathBuf, Vec<Issue>> {
+    let checked: Vec<(&PathBuf, Config)> = files
+        .iter()
+        .map(|path| (path, config.for_file(path)))
+        .filter(|(_, file_config)| file_config.checks.portable_filenames)
+        .collect();
+    let collisions = CaseCollisions::new(checked.iter().map(|&(path, _)| path.as_path()));
+
+    checked
+        .into_iter()
+        .filter_map(|(path, file_config)| {
+            let mut issues: Vec<Issue> = checked_components(path)
+                .iter()
+                .filter_map(|name| check_name(name))
+                .collect();
+            issues.extend(collisions.check(path));
+
+            let checker = CheckerCore::new(file_config);
+            let issues = checker.filter_issues(&Suppressions::new(), issues);
+            (!issues.is_empty()).then(|| (path.clone(), issues))
+        })
+        .collect()
+}
+
+/// The path components whose names are checked
+fn checked_components(path: &Path) -> Vec<String> {
+    let components: Vec<String> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+
+    if path.is_absolute() {
+        components.into_iter().last().into_iter().collect()
+    } else {
+        components
+    }
+}
+
+/// Check a single file or directory name
+fn check_name(name: &str) -> Option<Issue> {
+    let stem = name.split('.').next().unwrap_or(name).trim_end_matches(' ');
+
+    let (issue_type, message) = if RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+    {
+        (
+            IssueType::ReservedFilename,
+            format!("\"{name}\" is a reserved name on Windows"),
+        )
+    } else if let Some(c) = name
+        .chars()
+        .find(|&c| INVALID_CHARACTERS.contains(&c) || c.is_ascii_control())
+    {
+        (
+            IssueType::InvalidFilenameCharacter,
+            format!(
+                "\"{}\" contains U+{:04X}, which is not allowed in Windows file names",
+                name.escape_debug(),
+                u32::from(c)
+            ),
+        )
+    } else if name.ends_with(['.', ' ']) {
+        (
+            IssueType::FilenameTrailingDotOrSpace,
+            format!("\"{name}\" ends with a dot or space, which Windows strips"),
+        )
+    } else if !is_nfc(name) {
+        (
+            IssueType::NonNfcFilename,
+            format!("\"{name}\" is not in Unicode normalization form C"),
+        )
+    } else {
+        return None;
+    };
+
+    Some(file_issue(issue_type, message))
+}
+
+fn file_issue(issue_type: IssueType, message: String) -> Issue {
+    Issue {
+        issue_type,
+        line: None,
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
+        message,
+    }
+}
+
+/// Paths, and their leading directories, that differ only in case or
+/// Unicode normalization from another entry of their directory
+///
+/// Siblings are read from the file system rather than taken from the checked
+/// paths, so a new `readme.md` collides with an unchanged, filtered-out or
+/// ignored `README.md` too.
+struct CaseCollisions {
+    /// Names in each directory holding a checked path, keyed by folded name
+    entries: HashMap<PathBuf, HashMap<String, BTreeSet<OsString>>>,
+}
+
+impl CaseCollisions {
+    fn new<'a>(files: impl Iterator<Item = &'a Path>) -> Self {
+        let mut entries = HashMap::new();
+        for prefix in files.flat_map(prefixes) {
+            let dir = parent_dir(&prefix);
+            if let Entry::Vacant(entry) = entries.entry(dir) {
+                let names = read_names(entry.key());
+                entry.insert(names);
+            }
+        }
+        Self { entries }
+    }
+
+    /// Report the shortest prefix of `path` that has other spellings
+    fn check(&self, path: &Path) -> Option<Issue> {
+        prefixes(path).into_iter().find_map(|prefix| {
+            let name = prefix.file_name()?;
+            let others: Vec<String> = self
+                .entries
+                .get(&parent_dir(&prefix))?
+                .get(&fold(name))?
+                .iter()
+                .filter(|&other| other != name)
+                .map(|other| prefix.with_file_name(other).display().to_string())
+                .collect();
+            (!others.is_empty()).then(|| {
+                file_issue(
+                    IssueType::FilenameCaseCollision,
+                    format!(
+                        "\"{}\" differs only in case from \"{}\"",
+                        prefix.display(),
+                        others.join("\", \"")
+                    ),
+                )
+            })
+        })
+    }
+}
+
+/// `path` and each of its leading directories, shortest first; only the
+/// path itself if it is absolute
+fn prefixes(path: &Path) -> Vec<PathBuf> {
+    if path.is_absolute() {
+        return vec![path.to_path_buf()];
+    }
+
+    let mut prefixes: Vec<PathBuf> = path
+        .ancestors()
+        .filter(|prefix| matches!(prefix.components().next_back(), Some(Component::Normal(_))))
+        .map(Path::to_path_buf)
+        .collect();
+    prefixes.reverse();
+    prefixes
+}
+
+/// The directory holding `path`, `.` for a bare name
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// The entries of `dir`, grouped by folded name
+fn read_names(dir: &Path) -> HashMap<String, BTreeSet<OsString>> {
+    let mut names: HashMap<String, BTreeSet<OsString>> = HashMap::new();
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let name = entry.file_name();
+        names.entry(fold(&name)).or_default().insert(name);
+    }
+    names
+}
+
+/// The form in which names that a case-insensitive file system would
+/// confuse compare equal
+fn fold(name: &OsStr) -> String {
+    name.to_string_lossy()
+        .nfc()
+        .collect::<String>()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_type(name: &str) -> Option<IssueType> {
+        check_name(name).map(|issue| issue.issue_type)
+    }
+
+    #[test]
+    fn test_check_name() {
+        assert_eq!(issue_type("CON"), Some(IssueType::ReservedFilename));
+        assert_eq!(issue_type("aux.c"), Some(IssueType::ReservedFilename));
+        assert_eq!(issue_type("lpt1.tar.gz"), Some(IssueType::ReservedFilename));
+        assert_eq!(issue_type("console.c"), None);
+        assert_eq!(issue_type("com10"), None);
+
+        assert_eq!(
+            issue_type("a:b.txt"),
+            Some(IssueType::InvalidFilenameCharacter)
+        );
+        assert_eq!(
+            issue_type("why?.md"),
+            Some(IssueType::InvalidFilenameCharacter)
+        );
+        assert_eq!(
+            issue_type("tab\there"),
+            Some(IssueType::InvalidFilenameCharacter)
+        );
+
+        assert_eq!(
+            issue_type("notes."),
+            Some(IssueType::FilenameTrailingDotOrSpace)
+        );
+        assert_eq!(
+            issue_type("notes "),
+            Some(IssueType::FilenameTrailingDotOrSpace)
+        );
+        assert_eq!(issue_type(".gitignore"), None);
+
+        assert_eq!(
+            issue_type("cafe\u{301}.txt"),
+            Some(IssueType::NonNfcFilename)
+        );
+        assert_eq!(issue_type("caf\u{e9}.txt"), None);
+        assert_eq!(issue_type("main.rs"), None);
+    }
+
+    #[test]
+    fn test_checked_components() {
+        assert_eq!(
+            checked_components(Path::new("./aux/x.c")),
+            vec!["aux", "x.c"]
+        );
+        assert_eq!(checked_components(Path::new("/tmp/aux/x.c")), vec!["x.c"]);
+    }
+
+    #[test]
+    fn test_case_collisions() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for dir in ["src/Util", "src/util"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in [
+            "README.md",
+            "readme.md",
+            "src/Util/a.rs",
+            "src/util/b.rs",
+            "src/main.rs",
+        ] {
+            fs::write(root.join(file), "").unwrap();
+        }
+
+        // Siblings collide even when only one of them is checked
+        let readme = root.join("README.md");
+        let collisions = CaseCollisions::new([readme.as_path()].into_iter());
+        let issue = collisions.check(&readme).unwrap();
+        assert_eq!(issue.issue_type, IssueType::FilenameCaseCollision);
+        assert_eq!(
+            issue.message,
+            format!(
+                "\"{}\" differs only in case from \"{}\"",
+                readme.display(),
+                root.join("readme.md").display()
+            )
+        );
+
+        let b = root.join("src/util/b.rs");
+        let main = root.join("src/main.rs");
+        let collisions = CaseCollisions::new([b.as_path(), main.as_path()].into_iter());
+        assert!(
+            collisions.check(&b).is_none(),
+            "only the file name of absolute paths"
+        );
+        assert!(collisions.check(&main).is_none());
+    }
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(
+            prefixes(Path::new("src/util/b.rs")),
+            vec![
+                PathBuf::from("src"),
+                PathBuf::from("src/util"),
+                PathBuf::from("src/util/b.rs")
+            ]
+        );
+        assert_eq!(parent_dir(Path::new("src")), PathBuf::from("."));
+        assert_eq!(parent_dir(Path::new("src/util")), PathBuf::from("src"));
+    }
+
+    #[test]
+    fn test_check_paths_is_opt_in() {
+        let files = vec![PathBuf::from("aux.c")];
+        let mut config = Config::default();
+        assert!(check_paths(&files, &config).is_empty());
+
+        config.checks.portable_filenames = true;
+        let issues = &check_paths(&files, &config)[Path::new("aux.c")];
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::ReservedFilename);
+    }
+}
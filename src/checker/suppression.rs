@@ -0,0 +1,448 @@
+//! Inline suppression comments
+//!
+//! Markers such as `lineguard-disable-next-line trailing-space` are found
+//! anywhere in a line, so they work inside any comment syntax. A marker may be
+//! followed by rule codes or names separated by spaces or commas; without rule names it
+//! applies to every rule. Anything after `--` is treated as a free-form reason.
+
+use crate::checker::Column;
+use crate::{Issue, IssueType, Severity};
+
+/// Prefix shared by every suppression marker
+pub const MARKER_PREFIX: &str = "lineguard-";
+
+/// Kind of suppression marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectiveKind {
+    /// `lineguard-disable-next-line`: the line after the marker
+    DisableNextLine,
+    /// `lineguard-disable-line`: the line containing the marker
+    DisableLine,
+    /// `lineguard-disable`: every line until a matching `lineguard-enable`
+    Disable,
+    /// `lineguard-enable`: ends a `lineguard-disable` block
+    Enable,
+    /// `lineguard-disable-file`: the whole file, including file-level issues
+    DisableFile,
+}
+
+impl DirectiveKind {
+    fn parse(word: &str) -> Option<Self> {
+        match word {
+            "disable-next-line" => Some(DirectiveKind::DisableNextLine),
+            "disable-line" => Some(DirectiveKind::DisableLine),
+            "disable" => Some(DirectiveKind::Disable),
+            "enable" => Some(DirectiveKind::Enable),
+            "disable-file" => Some(DirectiveKind::DisableFile),
+            _ => None,
+        }
+    }
+
+    /// The marker as written in source files
+    pub fn marker(self) -> &'static str {
+        match self {
+            DirectiveKind::DisableNextLine => "lineguard-disable-next-line",
+            DirectiveKind::DisableLine => "lineguard-disable-line",
+            DirectiveKind::Disable => "lineguard-disable",
+            DirectiveKind::Enable => "lineguard-enable",
+            DirectiveKind::DisableFile => "lineguard-disable-file",
+        }
+    }
+}
+
+/// Rules a directive applies to
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleSet {
+    /// No rule names were given
+    All,
+    /// Only the named rules; unknown names are dropped
+    Only(Vec<IssueType>),
+}
+
+impl RuleSet {
+    /// Whether the set includes `issue_type`, or the rule it was split from
+    pub fn contains(&self, issue_type: IssueType) -> bool {
+        match self {
+            RuleSet::All => true,
+            RuleSet::Only(rules) => {
+                rules.contains(&issue_type)
+                    || issue_type
+                        .split_from()
+                        .is_some_and(|rule| rules.contains(&rule))
+            },
+        }
+    }
+
+    /// The rules in this set that are not in `other`
+    fn without(&self, other: &RuleSet) -> RuleSet {
+        let rules = match self {
+            RuleSet::All => IssueType::ALL,
+            RuleSet::Only(rules) => rules.as_slice(),
+        };
+        RuleSet::Only(
+            rules
+                .iter()
+                .copied()
+                .filter(|rule| !other.contains(*rule))
+                .collect(),
+        )
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, RuleSet::Only(rules) if rules.is_empty())
+    }
+}
+
+/// A suppression marker found in a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+    pub kind: DirectiveKind,
+    /// 1-based line the marker is on
+    pub line: usize,
+    /// Span of the marker itself within its line
+    pub column: Column,
+    pub end_column: Column,
+    pub rules: RuleSet,
+}
+
+/// A `lineguard-disable` block, covering `start..end` (`end` exclusive)
+#[derive(Debug, Clone)]
+struct Block {
+    directive: usize,
+    start: usize,
+    end: Option<usize>,
+    rules: RuleSet,
+}
+
+/// Suppression markers collected from a file, one line at a time
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    directives: Vec<Directive>,
+    blocks: Vec<Block>,
+}
+
+impl Suppressions {
+    /// Create an empty set of suppressions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect the suppressions of a whole file
+    pub fn parse(content: &str) -> Self {
+        let mut suppressions = Self::new();
+        for (line_num, line) in content.lines().enumerate() {
+            suppressions.scan_line(line, line_num + 1);
+        }
+        suppressions
+    }
+
+    /// Record any markers found on a line
+    pub fn scan_line(&mut self, line: &str, line_number: usize) {
+        for (offset, _) in line.match_indices(MARKER_PREFIX) {
+            let rest = &line[offset + MARKER_PREFIX.len()..];
+            let word_len = rest
+                .find(|c: char| !(c.is_ascii_lowercase() || c == '-'))
+                .unwrap_or(rest.len());
+            let Some(kind) = DirectiveKind::parse(&rest[..word_len]) else {
+                continue;
+            };
+
+            let rules = parse_rules(&rest[word_len..]);
+            let index = self.directives.len();
+            match kind {
+                DirectiveKind::Disable => self.blocks.push(Block {
+                    directive: index,
+                    start: line_number,
+                    end: None,
+                    rules: rules.clone(),
+                }),
+                DirectiveKind::Enable => self.close_blocks(&rules, line_number),
+                _ => {},
+            }
+
+            self.directives.push(Directive {
+                kind,
+                line: line_number,
+                column: Column::at(line, offset),
+                end_column: Column::at(line, offset + MARKER_PREFIX.len() + word_len),
+                rules,
+            });
+        }
+    }
+
+    /// End the open blocks for `rules`, keeping any other rules they disable
+    fn close_blocks(&mut self, rules: &RuleSet, line_number: usize) {
+        let mut reopened = Vec::new();
+        for block in self.blocks.iter_mut().filter(|b| b.end.is_none()) {
+            block.end = Some(line_number);
+            let remaining = block.rules.without(rules);
+            if !remaining.is_empty() {
+                reopened.push(Block {
+                    directive: block.directive,
+                    start: line_number,
+                    end: None,
+                    rules: remaining,
+                });
+            }
+        }
+        self.blocks.extend(reopened);
+    }
+
+    /// The markers found so far
+    pub fn directives(&self) -> &[Directive] {
+        &self.directives
+    }
+
+    /// Whether there are no markers at all
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Index of the directive that suppresses `issue_type` on `line`, if any
+    ///
+    /// Issues without a line can only be suppressed by `lineguard-disable-file`.
+    fn suppressed_by(&self, issue_type: IssueType, line: Option<usize>) -> Option<usize> {
+        let file_level = self
+            .directives
+            .iter()
+            .position(|d| d.kind == DirectiveKind::DisableFile && d.rules.contains(issue_type));
+        let Some(line) = line else {
+            return file_level;
+        };
+
+        file_level
+            .or_else(|| {
+                self.directives.iter().position(|d| {
+                    d.rules.contains(issue_type)
+                        && match d.kind {
+                            DirectiveKind::DisableLine => d.line == line,
+                            DirectiveKind::DisableNextLine => d.line + 1 == line,
+                            _ => false,
+                        }
+                })
+            })
+            .or_else(|| {
+                self.blocks
+                    .iter()
+                    .find(|b| {
+                        b.start <= line
+                            && b.end.is_none_or(|end| line < end)
+                            && b.rules.contains(issue_type)
+                    })
+                    .map(|b| b.directive)
+            })
+    }
+
+    /// Whether an issue of `issue_type` on `line` is suppressed
+    pub fn is_suppressed(&self, issue_type: IssueType, line: Option<usize>) -> bool {
+        self.suppressed_by(issue_type, line).is_some()
+    }
+
+    /// Drop suppressed issues, optionally reporting markers that suppressed nothing
+    pub fn filter(&self, issues: Vec<Issue>, report_unused: bool) -> Vec<Issue> {
+        if self.is_empty() {
+            return issues;
+        }
+
+        let mut used = vec![false; self.directives.len()];
+        let mut kept: Vec<Issue> = issues
+            .into_iter()
+            .filter(
+                |issue| match self.suppressed_by(issue.issue_type, issue.line) {
+                    Some(index) => {
+                        used[index] = true;
+                        false
+                    },
+                    None => true,
+                },
+            )
+            .collect();
+
+        if report_unused {
+            for (directive, _) in self
+                .directives
+                .iter()
+                .zip(&used)
+                .filter(|(d, used)| !**used && d.kind != DirectiveKind::Enable)
+            {
+                kept.push(Issue {
+                    issue_type: IssueType::UnusedSuppression,
+                    line: Some(directive.line),
+                    end_line: None,
+                    column: Some(directive.column),
+                    end_column: Some(directive.end_column),
+                    severity: Severity::Error,
+                    message: format!(
+                        "Unused suppression: {} does not suppress any issue",
+                        directive.kind.marker()
+                    ),
+                });
+            }
+        }
+
+        kept
+    }
+}
+
+/// Parse the rule names following a marker
+///
+/// Names are read until `--` or a token that cannot be a rule name, such as the
+/// end of a comment (`*/`, `-->`).
+fn parse_rules(text: &str) -> RuleSet {
+    let mut names = Vec::new();
+    for token in text.split(|c: char| c.is_whitespace() || c == ',') {
+        if token.is_empty() {
+            continue;
+        }
+        if token == "--"
+            || !token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            break;
+        }
+        names.push(token);
+    }
+
+    if names.is_empty() {
+        RuleSet::All
+    } else {
+        RuleSet::Only(names.into_iter().filter_map(IssueType::from_name).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(issue_type: IssueType, line: Option<usize>) -> Issue {
+        Issue {
+            issue_type,
+            line,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_markers_in_any_comment_syntax() {
+        let suppressions = Suppressions::parse(
+            "a // lineguard-disable-line trailing-space\n\
+             # lineguard-disable-next-line\n\
+             <!-- lineguard-disable trailing_space, missing-newline -->\n\
+             /* lineguard-enable */\n\
+             -- lineguard-disable-file -- generated fixture\n",
+        );
+        let directives = suppressions.directives();
+
+        assert_eq!(directives.len(), 5);
+        assert_eq!(directives[0].kind, DirectiveKind::DisableLine);
+        assert_eq!(directives[0].column.character, 6);
+        assert_eq!(directives[0].end_column.character, 28);
+        assert_eq!(
+            directives[0].rules,
+            RuleSet::Only(vec![IssueType::TrailingSpace])
+        );
+        assert_eq!(directives[1].rules, RuleSet::All);
+        assert_eq!(
+            directives[2].rules,
+            RuleSet::Only(vec![IssueType::TrailingSpace, IssueType::MissingNewline])
+        );
+        assert_eq!(directives[3].kind, DirectiveKind::Enable);
+        assert_eq!(directives[3].rules, RuleSet::All);
+        assert_eq!(directives[4].kind, DirectiveKind::DisableFile);
+        assert_eq!(directives[4].rules, RuleSet::All);
+    }
+
+    #[test]
+    fn test_unknown_markers_and_rules() {
+        let suppressions = Suppressions::parse("lineguard-disable-everything\n");
+        assert!(suppressions.is_empty());
+
+        // A directive naming only unknown rules suppresses nothing
+        let suppressions = Suppressions::parse("lineguard-disable-line trailing-spaces\n");
+        assert_eq!(suppressions.directives()[0].rules, RuleSet::Only(vec![]));
+        assert!(!suppressions.is_suppressed(IssueType::TrailingSpace, Some(1)));
+    }
+
+    #[test]
+    fn test_line_directives() {
+        let suppressions = Suppressions::parse(
+            "x lineguard-disable-line\n\
+             lineguard-disable-next-line trailing-space\n\
+             y\n\
+             z\n",
+        );
+
+        assert!(suppressions.is_suppressed(IssueType::TrailingSpace, Some(1)));
+        assert!(!suppressions.is_suppressed(IssueType::TrailingSpace, Some(2)));
+        assert!(suppressions.is_suppressed(IssueType::TrailingSpace, Some(3)));
+        assert!(!suppressions.is_suppressed(IssueType::CrlfLineEnding, Some(3)));
+        assert!(!suppressions.is_suppressed(IssueType::TrailingSpace, Some(4)));
+        assert!(!suppressions.is_suppressed(IssueType::MissingNewline, None));
+        // Whitespace-only lines were split out of trailing-space
+        assert!(suppressions.is_suppressed(IssueType::WhitespaceOnlyLine, Some(3)));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let suppressions = Suppressions::parse(
+            "a\n\
+             lineguard-disable\n\
+             b\n\
+             lineguard-enable trailing-space\n\
+             c\n\
+             lineguard-enable\n\
+             d\n",
+        );
+
+        assert!(!suppressions.is_suppressed(IssueType::TrailingSpace, Some(1)));
+        assert!(suppressions.is_suppressed(IssueType::TrailingSpace, Some(3)));
+        assert!(!suppressions.is_suppressed(IssueType::TrailingSpace, Some(5)));
+        assert!(suppressions.is_suppressed(IssueType::CrlfLineEnding, Some(5)));
+        assert!(!suppressions.is_suppressed(IssueType::CrlfLineEnding, Some(7)));
+    }
+
+    #[test]
+    fn test_unclosed_block_runs_to_end_of_file() {
+        let suppressions = Suppressions::parse("lineguard-disable trailing-space\na\nb\n");
+        assert!(suppressions.is_suppressed(IssueType::TrailingSpace, Some(3)));
+        assert!(!suppressions.is_suppressed(IssueType::MissingNewline, None));
+    }
+
+    #[test]
+    fn test_disable_file() {
+        let suppressions = Suppressions::parse("a\nlineguard-disable-file missing-newline\n");
+        assert!(suppressions.is_suppressed(IssueType::MissingNewline, None));
+        assert!(!suppressions.is_suppressed(IssueType::TrailingSpace, Some(1)));
+    }
+
+    #[test]
+    fn test_filter_reports_unused() {
+        let suppressions = Suppressions::parse(
+            "a  # lineguard-disable-line\n\
+             b # lineguard-disable-line crlf-line-ending\n\
+             lineguard-disable\n\
+             lineguard-enable\n",
+        );
+        let issues = vec![
+            issue(IssueType::TrailingSpace, Some(1)),
+            issue(IssueType::MissingNewline, None),
+        ];
+
+        let kept = suppressions.filter(issues.clone(), false);
+        assert_eq!(kept, vec![issue(IssueType::MissingNewline, None)]);
+
+        let kept = suppressions.filter(issues, true);
+        assert_eq!(kept.len(), 3);
+        assert_eq!(kept[0].issue_type, IssueType::MissingNewline);
+        assert_eq!(kept[1].issue_type, IssueType::UnusedSuppression);
+        assert_eq!(kept[1].line, Some(2));
+        assert_eq!(kept[1].column.unwrap().character, 5);
+        assert_eq!(kept[2].issue_type, IssueType::UnusedSuppression);
+        assert_eq!(kept[2].line, Some(3));
+    }
+}
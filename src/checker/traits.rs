@@ -32,7 +32,7 @@ pub trait ContentChecker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::IssueType;
+    use crate::{IssueType, Severity};
 
     struct MockLineChecker {
         config: Config,
@@ -44,6 +44,10 @@ mod tests {
                 Some(Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(line_number),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Trailing spaces found".to_string(),
                 })
             } else {
@@ -62,12 +66,20 @@ mod tests {
                 Some(Issue {
                     issue_type: IssueType::MissingNewline,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Missing newline at end of file".to_string(),
                 })
             } else if content.ends_with("\n\n") {
                 Some(Issue {
                     issue_type: IssueType::MultipleNewlines,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Multiple newlines at end of file".to_string(),
                 })
             } else {
@@ -1,3 +1,5 @@
+use crate::Severity;
+use crate::config::RuleSelector;
 use clap::{Parser, ValueEnum};
 use std::path::PathBuf;
 
@@ -7,6 +9,9 @@ pub enum OutputFormat {
     Json,
     #[value(name = "github")]
     GitHub,
+    Sarif,
+    #[value(name = "junit")]
+    JUnit,
 }
 
 #[derive(Parser, Debug)]
@@ -59,6 +64,45 @@ pub struct CliArgs {
     #[arg(long, help = "Disable trailing space check")]
     pub no_trailing_space: bool,
 
+    #[arg(
+        long,
+        value_name = "WIDTH",
+        help = "Report lines wider than this many columns"
+    )]
+    pub max_line_length: Option<usize>,
+
+    #[arg(
+        long,
+        value_name = "RULES",
+        value_delimiter = ',',
+        help = "Only report these rules, by code or name (comma-separated, `ALL` for every rule)"
+    )]
+    pub select: Option<Vec<RuleSelector>>,
+
+    #[arg(
+        long,
+        value_name = "RULES",
+        value_delimiter = ',',
+        help = "Report these rules in addition to the selected ones (comma-separated)"
+    )]
+    pub extend_select: Vec<RuleSelector>,
+
+    #[arg(
+        long,
+        value_name = "RULES",
+        value_delimiter = ',',
+        help = "Never report these rules, by code or name (comma-separated)"
+    )]
+    pub ignore_rule: Vec<RuleSelector>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "error",
+        help = "Exit with status 1 only for issues of at least this severity"
+    )]
+    pub fail_on: Severity,
+
     #[arg(long, help = "Automatically fix issues")]
     pub fix: bool,
 
@@ -74,8 +118,50 @@ pub struct CliArgs {
     )]
     pub to: Option<String>,
 
+    #[arg(
+        long,
+        requires = "from",
+        help = "Only report issues on lines changed in the git range (requires --from)"
+    )]
+    pub changed_lines_only: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["stdin", "from"],
+        help = "Check the staged content of files staged for commit (Git only)"
+    )]
+    pub staged: bool,
+
+    #[arg(
+        long,
+        help = "Report suppression comments that do not suppress any issue"
+    )]
+    pub report_unused_suppressions: bool,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with = "fix",
+        help = "Record the current issues in a baseline file (e.g. lineguard-baseline.json)"
+    )]
+    pub write_baseline: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with = "write_baseline",
+        help = "Don't report issues recorded in this baseline file"
+    )]
+    pub baseline: Option<PathBuf>,
+
     #[arg(long, help = "Skip hidden files (files starting with .)")]
     pub no_hidden: bool,
+
+    #[arg(
+        long,
+        help = "Don't respect .gitignore, .git/info/exclude or global git excludes"
+    )]
+    pub no_ignore_vcs: bool,
 }
 
 pub fn parse_args() -> CliArgs {
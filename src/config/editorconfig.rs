@@ -0,0 +1,198 @@
+//! EditorConfig support
+//!
+//! Maps the `.editorconfig` properties that apply to a file onto lineguard's
+//! checks, so policy already declared there does not need repeating in
+//! `.lineguardrc`.
+
+use crate::config::{BomPolicy, IndentStyle};
+use crate::config::{CheckOverrides, LineEndingStyle};
+use ec4rs::property::{
+    Charset, EndOfLine, FinalNewline, IndentSize, IndentStyle as EcIndentStyle, MaxLineLen,
+    TabWidth, TrimTrailingWs,
+};
+use std::path::Path;
+
+/// Check settings declared for `path` by its `.editorconfig` chain
+///
+/// Properties lineguard has no equivalent for, or that are unset, leave the
+/// corresponding check untouched. Unreadable or malformed files are ignored.
+pub fn check_overrides(path: &Path) -> CheckOverrides {
+    let mut overrides = CheckOverrides::default();
+    let Ok(properties) = ec4rs::properties_of(path) else {
+        return overrides;
+    };
+
+    if let Ok(FinalNewline::Value(insert)) = properties.get::<FinalNewline>() {
+        overrides.newline_ending = Some(insert);
+    }
+
+    if let Ok(TrimTrailingWs::Value(trim)) = properties.get::<TrimTrailingWs>() {
+        overrides.trailing_spaces = Some(trim);
+    }
+
+    match properties.get::<EndOfLine>() {
+        Ok(EndOfLine::Lf) => overrides.line_ending = Some(Some(LineEndingStyle::Lf)),
+        Ok(EndOfLine::CrLf) => overrides.line_ending = Some(Some(LineEndingStyle::Crlf)),
+        // Classic Mac line endings are not supported by the line ending check
+        _ => {},
+    }
+
+    match properties.get::<Charset>() {
+        Ok(Charset::Utf8) => {
+            overrides.require_utf8 = Some(true);
+            overrides.bom = Some(BomPolicy::Forbid);
+        },
+        Ok(Charset::Utf8Bom) => {
+            overrides.require_utf8 = Some(true);
+            overrides.bom = Some(BomPolicy::Require);
+        },
+        Ok(Charset::Latin1) => overrides.require_utf8 = Some(false),
+        // UTF-16 files are expected here, so their byte order marks are fine
+        Ok(Charset::Utf16Le | Charset::Utf16Be) => overrides.allow_utf16 = Some(true),
+        _ => {},
+    }
+
+    match properties.get::<MaxLineLen>() {
+        Ok(MaxLineLen::Value(width)) => overrides.max_line_length = Some(Some(width)),
+        Ok(MaxLineLen::Off) => overrides.max_line_length = Some(None),
+        _ => {},
+    }
+
+    // As in the EditorConfig spec, `tab_width` defaults to a numeric `indent_size`
+    match (properties.get::<TabWidth>(), properties.get::<IndentSize>()) {
+        (Ok(TabWidth::Value(width)), _) | (_, Ok(IndentSize::Value(width))) => {
+            overrides.tab_width = Some(width)
+        },
+        _ => {},
+    }
+
+    match properties.get::<EcIndentStyle>() {
+        Ok(EcIndentStyle::Spaces) => overrides.indent_style = Some(Some(IndentStyle::Spaces)),
+        Ok(EcIndentStyle::Tabs) => overrides.indent_style = Some(Some(IndentStyle::Tabs)),
+        _ => {},
+    }
+
+    // `indent_size = tab` means one level is as wide as a tab
+    match properties.get::<IndentSize>() {
+        Ok(IndentSize::Value(size)) => overrides.indent_size = Some(size),
+        Ok(IndentSize::UseTabWidth) => overrides.indent_size = overrides.tab_width,
+        _ => {},
+    }
+
+    overrides
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_maps_properties() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".editorconfig"),
+            "root = true\n\n[*]\ninsert_final_newline = true\ntrim_trailing_whitespace = true\nend_of_line = crlf\ncharset = utf-8\n\n[*.md]\ntrim_trailing_whitespace = false\n",
+        )
+        .unwrap();
+
+        let overrides = check_overrides(&temp_dir.path().join("main.rs"));
+        assert_eq!(overrides.newline_ending, Some(true));
+        assert_eq!(overrides.trailing_spaces, Some(true));
+        assert_eq!(overrides.line_ending, Some(Some(LineEndingStyle::Crlf)));
+        assert_eq!(overrides.require_utf8, Some(true));
+        assert_eq!(overrides.bom, Some(BomPolicy::Forbid));
+
+        // Later sections take precedence
+        let overrides = check_overrides(&temp_dir.path().join("README.md"));
+        assert_eq!(overrides.trailing_spaces, Some(false));
+        // Whitespace-only lines follow `trailing_spaces`
+        assert_eq!(overrides.whitespace_only_lines, None);
+    }
+
+    #[test]
+    fn test_nested_editorconfig_and_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let sub = temp_dir.path().join("sub");
+        fs::create_dir(&sub).unwrap();
+        fs::write(
+            temp_dir.path().join(".editorconfig"),
+            "root = true\n[*]\ninsert_final_newline = true\nend_of_line = lf\n",
+        )
+        .unwrap();
+        fs::write(
+            sub.join(".editorconfig"),
+            "[*]\ninsert_final_newline = false\n",
+        )
+        .unwrap();
+
+        let overrides = check_overrides(&sub.join("file.txt"));
+        assert_eq!(overrides.newline_ending, Some(false));
+        assert_eq!(overrides.line_ending, Some(Some(LineEndingStyle::Lf)));
+
+        // A nested root stops the search before reaching the parent
+        fs::write(
+            sub.join(".editorconfig"),
+            "root = true\n[*]\ncharset = latin1\n",
+        )
+        .unwrap();
+        let overrides = check_overrides(&sub.join("file.txt"));
+        assert_eq!(overrides.newline_ending, None);
+        assert_eq!(overrides.require_utf8, Some(false));
+    }
+
+    #[test]
+    fn test_unsupported_values_are_ignored() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".editorconfig"),
+            "root = true\n[*]\nend_of_line = cr\ncharset = utf-16le\n",
+        )
+        .unwrap();
+
+        let overrides = check_overrides(&temp_dir.path().join("file.txt"));
+        assert_eq!(overrides.line_ending, None);
+        assert_eq!(overrides.require_utf8, None);
+        assert_eq!(overrides.bom, None);
+        assert_eq!(overrides.allow_utf16, Some(true));
+    }
+
+    #[test]
+    fn test_maps_line_length_properties() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".editorconfig"),
+            "root = true\n[*]\nmax_line_length = 100\nindent_size = 2\n\n[*.go]\ntab_width = 8\n\n[*.md]\nmax_line_length = off\n",
+        )
+        .unwrap();
+
+        let overrides = check_overrides(&temp_dir.path().join("main.rs"));
+        assert_eq!(overrides.max_line_length, Some(Some(100)));
+        assert_eq!(overrides.tab_width, Some(2));
+
+        let overrides = check_overrides(&temp_dir.path().join("main.go"));
+        assert_eq!(overrides.tab_width, Some(8));
+
+        let overrides = check_overrides(&temp_dir.path().join("README.md"));
+        assert_eq!(overrides.max_line_length, Some(None));
+    }
+
+    #[test]
+    fn test_maps_indentation_properties() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".editorconfig"),
+            "root = true\n[*]\nindent_style = space\nindent_size = 2\n\n[Makefile]\nindent_style = tab\nindent_size = tab\ntab_width = 8\n",
+        )
+        .unwrap();
+
+        let overrides = check_overrides(&temp_dir.path().join("main.rs"));
+        assert_eq!(overrides.indent_style, Some(Some(IndentStyle::Spaces)));
+        assert_eq!(overrides.indent_size, Some(2));
+
+        let overrides = check_overrides(&temp_dir.path().join("Makefile"));
+        assert_eq!(overrides.indent_style, Some(Some(IndentStyle::Tabs)));
+        assert_eq!(overrides.indent_size, Some(8));
+    }
+}
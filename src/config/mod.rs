@@ -1,8 +1,16 @@
-use serde::{Deserialize, Serialize};
+pub mod editorconfig;
+
+use crate::{IssueType, Severity};
+use glob::Pattern;
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
     #[serde(default)]
@@ -11,6 +19,536 @@ pub struct Config {
     pub ignore_patterns: Vec<String>,
     #[serde(default)]
     pub file_extensions: Vec<String>,
+    #[serde(default)]
+    pub binary_detection: BinaryDetection,
+    /// Replaces the built-in list of binary file extensions when set
+    #[serde(default)]
+    pub binary_extensions: Option<Vec<String>>,
+    /// Extra binary file extensions added to the built-in (or configured) list
+    #[serde(default)]
+    pub extend_binary_extensions: Vec<String>,
+    /// Per-path check settings, applied in order on top of `checks`
+    #[serde(default)]
+    pub overrides: Vec<Override>,
+    /// Take check settings from `.editorconfig` where `[checks]` leaves them unset
+    #[serde(default = "default_true")]
+    pub editorconfig: bool,
+    /// Report suppression comments that do not suppress any issue
+    #[serde(default)]
+    pub report_unused_suppressions: bool,
+    /// Rules to report, by code or name; every rule when unset
+    #[serde(default)]
+    pub select: Option<Vec<RuleSelector>>,
+    /// Rules never reported, even when selected
+    #[serde(default)]
+    pub ignore: Vec<RuleSelector>,
+    /// Severity of each rule, or of every rule under `ALL`
+    #[serde(default)]
+    pub severity: HashMap<RuleSelector, Severity>,
+    /// The `checks` fields set explicitly in the config file or on the command line
+    #[serde(skip)]
+    pub explicit_checks: CheckOverrides,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            checks: CheckConfig::default(),
+            ignore_patterns: Vec::new(),
+            file_extensions: Vec::new(),
+            binary_detection: BinaryDetection::default(),
+            binary_extensions: None,
+            extend_binary_extensions: Vec::new(),
+            overrides: Vec::new(),
+            editorconfig: true,
+            report_unused_suppressions: false,
+            select: None,
+            ignore: Vec::new(),
+            severity: HashMap::new(),
+            explicit_checks: CheckOverrides::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Whether issues of `issue_type` are reported under `select` and `ignore`
+    pub fn is_rule_selected(&self, issue_type: IssueType) -> bool {
+        let selected = self
+            .select
+            .as_ref()
+            .is_none_or(|select| select.iter().any(|s| s.matches(issue_type)));
+        selected && !self.ignore.iter().any(|s| s.matches(issue_type))
+    }
+
+    /// Severity of `issue_type`: its own `severity` entry, else the `ALL`
+    /// entry, else the rule's default
+    pub fn severity_for(&self, issue_type: IssueType) -> Severity {
+        self.severity
+            .get(&RuleSelector::Rule(issue_type))
+            .or_else(|| self.severity.get(&RuleSelector::All))
+            .copied()
+            .unwrap_or_else(|| issue_type.default_severity())
+    }
+
+    /// Check settings for `path`, with every matching override applied in order
+    pub fn checks_for(&self, path: &Path) -> CheckConfig {
+        let mut checks = self.checks.clone();
+        if self.editorconfig {
+            editorconfig::check_overrides(path)
+                .without(&self.explicit_checks)
+                .apply(&mut checks);
+        }
+        for override_ in &self.overrides {
+            if crate::discovery::matches_patterns(path, override_.patterns()) {
+                override_.checks.apply(&mut checks);
+            }
+        }
+        // Hard breaks only mean something in Markdown
+        checks.markdown_hard_breaks &= is_markdown(path);
+        checks
+    }
+
+    /// This configuration with the checks resolved for `path`
+    pub fn for_file(&self, path: &Path) -> Config {
+        if self.overrides.is_empty() && !self.editorconfig && !self.checks.markdown_hard_breaks {
+            return self.clone();
+        }
+
+        Config {
+            checks: self.checks_for(path),
+            ..self.clone()
+        }
+    }
+}
+
+/// Whether `path` is a Markdown file, judged by its extension
+fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+/// An `[[overrides]]` section: check settings for files matching any of `files`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Override {
+    /// Glob patterns, matched like `ignore_patterns`
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub checks: CheckOverrides,
+    /// `files`, compiled on first use; see [`Override::patterns`]
+    #[serde(skip)]
+    patterns: OnceLock<Vec<Pattern>>,
+}
+
+impl Override {
+    /// An override applying `checks` to files matching any of `files`
+    pub fn new(files: Vec<String>, checks: CheckOverrides) -> Self {
+        Self {
+            files,
+            checks,
+            patterns: OnceLock::new(),
+        }
+    }
+
+    /// The compiled `files` patterns
+    ///
+    /// Config files reject invalid patterns when they are loaded; in overrides
+    /// built any other way, invalid patterns match nothing.
+    fn patterns(&self) -> &[Pattern] {
+        self.patterns
+            .get_or_init(|| crate::discovery::compile_patterns(&self.files))
+    }
+}
+
+/// Check settings an override can change; unset fields keep the inherited value
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckOverrides {
+    pub newline_ending: Option<bool>,
+    pub trailing_spaces: Option<bool>,
+    /// A style, or `false` to disable the line ending check
+    #[serde(deserialize_with = "deserialize_line_ending_override")]
+    pub line_ending: Option<Option<LineEndingStyle>>,
+    pub require_utf8: Option<bool>,
+    /// A width, or `false` to disable the line length check
+    #[serde(deserialize_with = "deserialize_max_line_length_override")]
+    pub max_line_length: Option<Option<usize>>,
+    pub tab_width: Option<usize>,
+    pub line_length_ignore_urls: Option<bool>,
+    pub line_length_ignore_pattern: Option<LinePattern>,
+    /// A style, or `false` to disable the indentation style check
+    #[serde(deserialize_with = "deserialize_indent_style_override")]
+    pub indent_style: Option<Option<IndentStyle>>,
+    pub indent_size: Option<usize>,
+    pub mixed_indentation: Option<bool>,
+    pub unicode_hazards: Option<bool>,
+    pub bom: Option<BomPolicy>,
+    pub conflict_markers: Option<bool>,
+    /// A count, or `false` to disable the consecutive blank lines check
+    #[serde(deserialize_with = "deserialize_max_consecutive_blank_lines_override")]
+    pub max_consecutive_blank_lines: Option<Option<usize>>,
+    pub leading_blank_lines: Option<bool>,
+    pub whitespace_only_lines: Option<bool>,
+    pub markdown_hard_breaks: Option<bool>,
+    pub control_characters: Option<bool>,
+    pub allowed_control_characters: Option<Vec<char>>,
+    pub fix_control_characters: Option<bool>,
+    pub portable_filenames: Option<bool>,
+    /// Set from `.editorconfig` `charset = utf-16le` or `utf-16be`
+    #[serde(skip)]
+    pub allow_utf16: Option<bool>,
+}
+
+impl CheckOverrides {
+    /// Apply the fields set in this override to `checks`
+    pub fn apply(&self, checks: &mut CheckConfig) {
+        if let Some(newline_ending) = self.newline_ending {
+            checks.newline_ending = newline_ending;
+        }
+        if let Some(trailing_spaces) = self.trailing_spaces {
+            checks.trailing_spaces = trailing_spaces;
+        }
+        if let Some(line_ending) = self.line_ending {
+            checks.line_ending = line_ending;
+        }
+        if let Some(require_utf8) = self.require_utf8 {
+            checks.require_utf8 = require_utf8;
+        }
+        if let Some(max_line_length) = self.max_line_length {
+            checks.max_line_length = max_line_length;
+        }
+        if let Some(tab_width) = self.tab_width {
+            checks.tab_width = tab_width;
+        }
+        if let Some(ignore_urls) = self.line_length_ignore_urls {
+            checks.line_length_ignore_urls = ignore_urls;
+        }
+        if let Some(pattern) = &self.line_length_ignore_pattern {
+            checks.line_length_ignore_pattern = Some(pattern.clone());
+        }
+        if let Some(indent_style) = self.indent_style {
+            checks.indent_style = indent_style;
+        }
+        if let Some(indent_size) = self.indent_size {
+            checks.indent_size = indent_size;
+        }
+        if let Some(mixed_indentation) = self.mixed_indentation {
+            checks.mixed_indentation = mixed_indentation;
+        }
+        if let Some(unicode_hazards) = self.unicode_hazards {
+            checks.unicode_hazards = unicode_hazards;
+        }
+        if let Some(bom) = self.bom {
+            checks.bom = bom;
+        }
+        if let Some(conflict_markers) = self.conflict_markers {
+            checks.conflict_markers = conflict_markers;
+        }
+        if let Some(max_consecutive_blank_lines) = self.max_consecutive_blank_lines {
+            checks.max_consecutive_blank_lines = max_consecutive_blank_lines;
+        }
+        if let Some(leading_blank_lines) = self.leading_blank_lines {
+            checks.leading_blank_lines = leading_blank_lines;
+        }
+        if let Some(whitespace_only_lines) = self.whitespace_only_lines {
+            checks.whitespace_only_lines = Some(whitespace_only_lines);
+        }
+        if let Some(markdown_hard_breaks) = self.markdown_hard_breaks {
+            checks.markdown_hard_breaks = markdown_hard_breaks;
+        }
+        if let Some(control_characters) = self.control_characters {
+            checks.control_characters = control_characters;
+        }
+        if let Some(allowed_control_characters) = &self.allowed_control_characters {
+            checks.allowed_control_characters = allowed_control_characters.clone();
+        }
+        if let Some(fix_control_characters) = self.fix_control_characters {
+            checks.fix_control_characters = fix_control_characters;
+        }
+        if let Some(portable_filenames) = self.portable_filenames {
+            checks.portable_filenames = portable_filenames;
+        }
+        if let Some(allow_utf16) = self.allow_utf16 {
+            checks.allow_utf16 = allow_utf16;
+        }
+    }
+
+    /// This override with every field that is set in `other` unset
+    pub fn without(mut self, other: &CheckOverrides) -> CheckOverrides {
+        if other.newline_ending.is_some() {
+            self.newline_ending = None;
+        }
+        if other.trailing_spaces.is_some() {
+            self.trailing_spaces = None;
+        }
+        if other.line_ending.is_some() {
+            self.line_ending = None;
+        }
+        if other.require_utf8.is_some() {
+            self.require_utf8 = None;
+        }
+        if other.max_line_length.is_some() {
+            self.max_line_length = None;
+        }
+        if other.tab_width.is_some() {
+            self.tab_width = None;
+        }
+        if other.line_length_ignore_urls.is_some() {
+            self.line_length_ignore_urls = None;
+        }
+        if other.line_length_ignore_pattern.is_some() {
+            self.line_length_ignore_pattern = None;
+        }
+        if other.indent_style.is_some() {
+            self.indent_style = None;
+        }
+        if other.indent_size.is_some() {
+            self.indent_size = None;
+        }
+        if other.mixed_indentation.is_some() {
+            self.mixed_indentation = None;
+        }
+        if other.unicode_hazards.is_some() {
+            self.unicode_hazards = None;
+        }
+        if other.bom.is_some() {
+            self.bom = None;
+        }
+        if other.conflict_markers.is_some() {
+            self.conflict_markers = None;
+        }
+        if other.max_consecutive_blank_lines.is_some() {
+            self.max_consecutive_blank_lines = None;
+        }
+        if other.leading_blank_lines.is_some() {
+            self.leading_blank_lines = None;
+        }
+        if other.whitespace_only_lines.is_some() {
+            self.whitespace_only_lines = None;
+        }
+        if other.markdown_hard_breaks.is_some() {
+            self.markdown_hard_breaks = None;
+        }
+        if other.control_characters.is_some() {
+            self.control_characters = None;
+        }
+        if other.allowed_control_characters.is_some() {
+            self.allowed_control_characters = None;
+        }
+        if other.fix_control_characters.is_some() {
+            self.fix_control_characters = None;
+        }
+        if other.portable_filenames.is_some() {
+            self.portable_filenames = None;
+        }
+        if other.allow_utf16.is_some() {
+            self.allow_utf16 = None;
+        }
+        self
+    }
+}
+
+fn deserialize_line_ending_override<'de, D>(
+    deserializer: D,
+) -> Result<Option<Option<LineEndingStyle>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Setting {
+        Enabled(bool),
+        Style(LineEndingStyle),
+    }
+
+    match Setting::deserialize(deserializer)? {
+        Setting::Enabled(false) => Ok(Some(None)),
+        Setting::Enabled(true) => Err(serde::de::Error::custom(
+            "line_ending = true is ambiguous, use \"lf\", \"crlf\", \"native\" or \"consistent\"",
+        )),
+        Setting::Style(style) => Ok(Some(Some(style))),
+    }
+}
+
+fn deserialize_max_line_length_override<'de, D>(
+    deserializer: D,
+) -> Result<Option<Option<usize>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Setting {
+        Enabled(bool),
+        Width(usize),
+    }
+
+    match Setting::deserialize(deserializer)? {
+        Setting::Enabled(false) => Ok(Some(None)),
+        Setting::Enabled(true) => Err(serde::de::Error::custom(
+            "max_line_length = true is ambiguous, use a width",
+        )),
+        Setting::Width(width) => Ok(Some(Some(width))),
+    }
+}
+
+fn deserialize_max_consecutive_blank_lines_override<'de, D>(
+    deserializer: D,
+) -> Result<Option<Option<usize>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Setting {
+        Enabled(bool),
+        Count(usize),
+    }
+
+    match Setting::deserialize(deserializer)? {
+        Setting::Enabled(false) => Ok(Some(None)),
+        Setting::Enabled(true) => Err(serde::de::Error::custom(
+            "max_consecutive_blank_lines = true is ambiguous, use a count",
+        )),
+        Setting::Count(count) => Ok(Some(Some(count))),
+    }
+}
+
+fn deserialize_indent_style_override<'de, D>(
+    deserializer: D,
+) -> Result<Option<Option<IndentStyle>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Setting {
+        Enabled(bool),
+        Style(IndentStyle),
+    }
+
+    match Setting::deserialize(deserializer)? {
+        Setting::Enabled(false) => Ok(Some(None)),
+        Setting::Enabled(true) => Err(serde::de::Error::custom(
+            "indent_style = true is ambiguous, use \"spaces\" or \"tabs\"",
+        )),
+        Setting::Style(style) => Ok(Some(Some(style))),
+    }
+}
+
+/// A regular expression matched against the content of a line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct LinePattern(Regex);
+
+impl LinePattern {
+    /// Whether the pattern matches anywhere in `line`
+    pub fn is_match(&self, line: &str) -> bool {
+        self.0.is_match(line)
+    }
+}
+
+impl PartialEq for LinePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl FromStr for LinePattern {
+    type Err = regex::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Regex::new(s).map(LinePattern)
+    }
+}
+
+impl TryFrom<String> for LinePattern {
+    type Error = regex::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<LinePattern> for String {
+    fn from(pattern: LinePattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+/// A rule, or every rule, named in `select` or `ignore`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum RuleSelector {
+    /// `ALL`
+    All,
+    /// A rule code such as `LG003`, or a name such as `trailing-space`
+    Rule(IssueType),
+}
+
+impl RuleSelector {
+    /// Whether the selector includes `issue_type`
+    pub fn matches(self, issue_type: IssueType) -> bool {
+        match self {
+            RuleSelector::All => true,
+            RuleSelector::Rule(rule) => rule == issue_type,
+        }
+    }
+}
+
+impl FromStr for RuleSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(RuleSelector::All);
+        }
+        IssueType::from_name(s)
+            .map(RuleSelector::Rule)
+            .ok_or_else(|| format!("unknown rule `{s}`"))
+    }
+}
+
+impl TryFrom<String> for RuleSelector {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<RuleSelector> for String {
+    fn from(selector: RuleSelector) -> Self {
+        match selector {
+            RuleSelector::All => "ALL".to_string(),
+            RuleSelector::Rule(rule) => rule.code().to_string(),
+        }
+    }
+}
+
+/// How binary files are recognised and skipped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BinaryDetection {
+    /// Skip files whose extension is in the binary extension list
+    Extension,
+    /// Skip files whose first bytes look binary
+    Content,
+    /// Skip files matching either of the above
+    #[default]
+    Both,
+}
+
+impl BinaryDetection {
+    /// Whether binary files are recognised by extension
+    pub fn uses_extension(self) -> bool {
+        matches!(self, BinaryDetection::Extension | BinaryDetection::Both)
+    }
+
+    /// Whether binary files are recognised by sniffing their content
+    pub fn uses_content(self) -> bool {
+        matches!(self, BinaryDetection::Content | BinaryDetection::Both)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,17 +558,174 @@ pub struct CheckConfig {
     pub newline_ending: bool,
     #[serde(default = "default_true")]
     pub trailing_spaces: bool,
+    #[serde(default)]
+    pub line_ending: Option<LineEndingStyle>,
+    #[serde(default)]
+    pub require_utf8: bool,
+    /// Maximum display width of a line; no limit when unset
+    #[serde(default)]
+    pub max_line_length: Option<usize>,
+    /// Columns between tab stops when measuring line width
+    #[serde(default = "default_tab_width")]
+    pub tab_width: usize,
+    /// Exempt lines that are only a URL from the line length check
+    #[serde(default = "default_true")]
+    pub line_length_ignore_urls: bool,
+    /// Exempt lines matching this regular expression from the line length check
+    #[serde(default)]
+    pub line_length_ignore_pattern: Option<LinePattern>,
+    /// Character lines must be indented with; not checked when unset
+    #[serde(default)]
+    pub indent_style: Option<IndentStyle>,
+    /// Columns per indentation level, used to convert between tabs and spaces
+    #[serde(default = "default_indent_size")]
+    pub indent_size: usize,
+    /// Report lines whose indentation mixes tabs and spaces
+    #[serde(default)]
+    pub mixed_indentation: bool,
+    /// Report bidirectional controls, zero-width, non-breaking and other
+    /// invisible characters
+    #[serde(default = "default_true")]
+    pub unicode_hazards: bool,
+    /// Whether files must, must not or may start with a byte order mark
+    #[serde(default)]
+    pub bom: BomPolicy,
+    /// Report merge conflict markers left at the start of a line
+    #[serde(default = "default_true")]
+    pub conflict_markers: bool,
+    /// Most blank lines allowed in a row; no limit when unset
+    #[serde(default)]
+    pub max_consecutive_blank_lines: Option<usize>,
+    /// Report blank lines at the start of a file
+    #[serde(default)]
+    pub leading_blank_lines: bool,
+    /// Report lines holding nothing but whitespace; follows `trailing_spaces`
+    /// when unset
+    #[serde(default)]
+    pub whitespace_only_lines: Option<bool>,
+    /// Allow exactly two trailing spaces, a hard line break, in Markdown files
+    #[serde(default)]
+    pub markdown_hard_breaks: bool,
+    /// Report C0 and C1 control characters other than tab and line feed
+    #[serde(default = "default_true")]
+    pub control_characters: bool,
+    /// Control characters not to report; form feed, a page break in C
+    /// sources and GNU-style files, by default
+    #[serde(default = "default_allowed_control_characters")]
+    pub allowed_control_characters: Vec<char>,
+    /// Delete reported control characters when fixing
+    #[serde(default)]
+    pub fix_control_characters: bool,
+    /// Report file names that are not portable to Windows and macOS
+    #[serde(default)]
+    pub portable_filenames: bool,
+    /// Accept UTF-16 files; set only by `.editorconfig`, as the rest of the
+    /// configuration assumes files are UTF-8 or a legacy 8-bit encoding
+    #[serde(skip)]
+    pub allow_utf16: bool,
+}
+
+impl CheckConfig {
+    /// Whether whitespace-only lines are reported: as set, or else like
+    /// trailing whitespace, which they were once reported as
+    pub fn reports_whitespace_only_lines(&self) -> bool {
+        self.whitespace_only_lines.unwrap_or(self.trailing_spaces)
+    }
+}
+
+/// Byte order mark policy for the BOM check
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BomPolicy {
+    /// Report files starting with a byte order mark
+    Forbid,
+    /// Report non-empty files not starting with a UTF-8 byte order mark
+    Require,
+    /// Don't check byte order marks
+    #[default]
+    Ignore,
+}
+
+/// Character used for indentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndentStyle {
+    /// Indent with spaces only
+    #[serde(alias = "space")]
+    Spaces,
+    /// Indent with tabs, optionally followed by fewer than `indent_size`
+    /// spaces for alignment
+    #[serde(alias = "tab")]
+    Tabs,
+}
+
+/// Expected line terminator style for the line ending check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndingStyle {
+    /// Every line must end with `\n`
+    Lf,
+    /// Every line must end with `\r\n`
+    Crlf,
+    /// Every line must use the platform's native line ending
+    Native,
+    /// Any line ending, as long as the whole file uses the same one
+    Consistent,
+}
+
+impl LineEndingStyle {
+    /// Resolve `Native` to the concrete style of the current platform
+    pub fn resolve(self) -> Self {
+        match self {
+            LineEndingStyle::Native if cfg!(windows) => LineEndingStyle::Crlf,
+            LineEndingStyle::Native => LineEndingStyle::Lf,
+            other => other,
+        }
+    }
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_tab_width() -> usize {
+    4
+}
+
+fn default_indent_size() -> usize {
+    4
+}
+
+fn default_allowed_control_characters() -> Vec<char> {
+    vec!['\x0c']
+}
+
 impl Default for CheckConfig {
     fn default() -> Self {
         Self {
             newline_ending: true,
             trailing_spaces: true,
+            line_ending: None,
+            require_utf8: false,
+            max_line_length: None,
+            tab_width: default_tab_width(),
+            line_length_ignore_urls: true,
+            line_length_ignore_pattern: None,
+            indent_style: None,
+            indent_size: default_indent_size(),
+            mixed_indentation: false,
+            unicode_hazards: true,
+            bom: BomPolicy::default(),
+            conflict_markers: true,
+            max_consecutive_blank_lines: None,
+            leading_blank_lines: false,
+            whitespace_only_lines: None,
+            markdown_hard_breaks: false,
+            control_characters: true,
+            allowed_control_characters: default_allowed_control_characters(),
+            fix_control_characters: false,
+            portable_filenames: false,
+            allow_utf16: false,
         }
     }
 }
@@ -40,8 +735,7 @@ pub fn load_config(explicit_path: Option<&Path>) -> Result<Config, anyhow::Error
     if let Some(path) = explicit_path {
         if path.exists() {
             let content = fs::read_to_string(path)?;
-            let config: Config = toml::from_str(&content)?;
-            return Ok(config);
+            return parse_config(&content);
         } else {
             return Err(anyhow::anyhow!(
                 "Configuration file not found: {}",
@@ -54,14 +748,37 @@ pub fn load_config(explicit_path: Option<&Path>) -> Result<Config, anyhow::Error
     let config_path = find_config_file()?;
     if let Some(path) = config_path {
         let content = fs::read_to_string(&path)?;
-        let config: Config = toml::from_str(&content)?;
-        Ok(config)
+        parse_config(&content)
     } else {
         // No config file found, use defaults
         Ok(Config::default())
     }
 }
 
+/// Parse a config file, recording which `[checks]` fields it sets explicitly
+fn parse_config(content: &str) -> Result<Config, anyhow::Error> {
+    #[derive(Deserialize)]
+    struct ExplicitChecks {
+        #[serde(default)]
+        checks: CheckOverrides,
+    }
+
+    let mut config: Config = toml::from_str(content)?;
+    config.explicit_checks = toml::from_str::<ExplicitChecks>(content)?.checks;
+    for override_ in &config.overrides {
+        if let Some((file, e)) = override_
+            .files
+            .iter()
+            .find_map(|file| Pattern::new(file).err().map(|e| (file, e)))
+        {
+            return Err(anyhow::anyhow!(
+                "Invalid pattern \"{file}\" in [[overrides]] files: {e}"
+            ));
+        }
+    }
+    Ok(config)
+}
+
 fn find_config_file() -> Result<Option<PathBuf>, anyhow::Error> {
     let current_dir = std::env::current_dir()?;
     let mut dir = current_dir.as_path();
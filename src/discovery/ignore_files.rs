@@ -0,0 +1,225 @@
+use ignore::Match;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::path::{Path, PathBuf};
+
+/// Per-directory ignore file honoured regardless of `--no-ignore-vcs`
+pub const LINEGUARD_IGNORE_FILE: &str = ".lineguardignore";
+
+/// Stack of gitignore-style matchers tracking the directory being walked.
+///
+/// Each directory contributes one matcher built from its `.gitignore`, `.ignore`
+/// and `.lineguardignore` (later files take precedence). Deeper directories take
+/// precedence over their parents, and `.git/info/exclude` and the global git
+/// excludes file are consulted last.
+pub struct IgnoreStack {
+    respect_vcs: bool,
+    repo_root: Option<PathBuf>,
+    base: Vec<Gitignore>,
+    dirs: Vec<Gitignore>,
+}
+
+impl IgnoreStack {
+    /// Create a stack for a walk starting at `start`, preloading the ignore
+    /// files of every ancestor up to the enclosing repository root.
+    pub fn new(start: &Path, respect_vcs: bool) -> Self {
+        let start = absolute(start);
+        let repo_root = find_repo_root(&start);
+
+        let mut base = Vec::new();
+        if respect_vcs && let Some(root) = &repo_root {
+            let mut builder = GitignoreBuilder::new(root);
+            builder.add(root.join(".git").join("info").join("exclude"));
+            base.push(builder.build().unwrap_or_else(|_| Gitignore::empty()));
+            base.push(Gitignore::global().0);
+        }
+
+        let mut stack = Self {
+            respect_vcs,
+            repo_root: repo_root.clone(),
+            base,
+            dirs: Vec::new(),
+        };
+
+        if let Some(root) = repo_root {
+            let mut ancestors: Vec<&Path> = start
+                .ancestors()
+                .skip(1)
+                .take_while(|dir| dir.starts_with(&root))
+                .collect();
+            ancestors.reverse();
+            for dir in ancestors {
+                stack.push_dir(dir);
+            }
+        }
+
+        stack
+    }
+
+    /// Enter `dir`, loading whatever ignore files it contains.
+    pub fn push_dir(&mut self, dir: &Path) {
+        let dir = absolute(dir);
+        let mut builder = GitignoreBuilder::new(&dir);
+        let mut names = Vec::with_capacity(3);
+        if self.respect_vcs {
+            names.push(".gitignore");
+        }
+        names.push(".ignore");
+        names.push(LINEGUARD_IGNORE_FILE);
+
+        for name in names {
+            let file = dir.join(name);
+            if file.is_file()
+                && let Some(e) = builder.add(&file)
+            {
+                eprintln!("{}: {}", file.display(), e);
+            }
+        }
+
+        self.dirs
+            .push(builder.build().unwrap_or_else(|_| Gitignore::empty()));
+    }
+
+    /// Leave the directory most recently entered with [`push_dir`](Self::push_dir).
+    pub fn pop_dir(&mut self) {
+        self.dirs.pop();
+    }
+
+    /// Whether `path` is excluded by the ignore files currently in scope
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.respect_vcs && is_dir && path.file_name().is_some_and(|name| name == ".git") {
+            return true;
+        }
+
+        let path = absolute(path);
+        for matcher in self.dirs.iter().rev().chain(self.base.iter()) {
+            // Parents count too, for walks that start inside an ignored
+            // directory, but can only be walked up to the matcher's root
+            let (candidate, under_root) = if matcher.path().as_os_str().is_empty() {
+                // The global excludes file has no root; match relative to the repo
+                match self.repo_root.as_ref().map(|root| path.strip_prefix(root)) {
+                    Some(Ok(relative)) => (relative, true),
+                    _ => (path.as_path(), false),
+                }
+            } else if path.starts_with(matcher.path()) {
+                (path.as_path(), true)
+            } else {
+                continue;
+            };
+
+            let matched = if under_root {
+                matcher.matched_path_or_any_parents(candidate, is_dir)
+            } else {
+                matcher.matched(candidate, is_dir)
+            };
+            match matched {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {},
+            }
+        }
+
+        false
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn find_repo_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_negation_whitelists_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "*.log\n!keep.log\n").unwrap();
+
+        let mut stack = IgnoreStack::new(root, true);
+        stack.push_dir(root);
+
+        assert!(stack.is_ignored(&root.join("debug.log"), false));
+        assert!(!stack.is_ignored(&root.join("keep.log"), false));
+        assert!(!stack.is_ignored(&root.join("main.rs"), false));
+    }
+
+    #[test]
+    fn test_nested_file_overrides_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let sub = root.join("sub");
+        fs::create_dir(&sub).unwrap();
+        fs::write(root.join(".gitignore"), "*.txt\n").unwrap();
+        fs::write(sub.join(".ignore"), "!*.txt\n").unwrap();
+
+        let mut stack = IgnoreStack::new(root, true);
+        stack.push_dir(root);
+        assert!(stack.is_ignored(&root.join("a.txt"), false));
+
+        stack.push_dir(&sub);
+        assert!(!stack.is_ignored(&sub.join("a.txt"), false));
+
+        stack.pop_dir();
+        assert!(stack.is_ignored(&root.join("b.txt"), false));
+    }
+
+    #[test]
+    fn test_no_ignore_vcs_skips_gitignore_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "a.txt\n").unwrap();
+        fs::write(root.join(LINEGUARD_IGNORE_FILE), "b.txt\n").unwrap();
+
+        let mut stack = IgnoreStack::new(root, false);
+        stack.push_dir(root);
+
+        assert!(!stack.is_ignored(&root.join("a.txt"), false));
+        assert!(stack.is_ignored(&root.join("b.txt"), false));
+        assert!(!stack.is_ignored(&root.join(".git"), true));
+    }
+
+    #[test]
+    fn test_preloads_ancestors_within_repo() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let sub = root.join("sub");
+        fs::create_dir_all(root.join(".git").join("info")).unwrap();
+        fs::create_dir(&sub).unwrap();
+        fs::write(root.join(".gitignore"), "*.tmp\n").unwrap();
+        fs::write(root.join(".git/info/exclude"), "*.bak\n").unwrap();
+
+        let mut stack = IgnoreStack::new(&sub, true);
+        stack.push_dir(&sub);
+
+        assert!(stack.is_ignored(&sub.join("x.tmp"), false));
+        assert!(stack.is_ignored(&sub.join("x.bak"), false));
+        assert!(stack.is_ignored(&root.join(".git"), true));
+    }
+
+    #[test]
+    fn test_walk_inside_ignored_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let build = root.join("build");
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir(&build).unwrap();
+        fs::write(root.join(".gitignore"), "build/\n").unwrap();
+
+        let mut stack = IgnoreStack::new(&build, true);
+        stack.push_dir(&build);
+
+        assert!(stack.is_ignored(&build.join("out.c"), false));
+        assert!(stack.is_ignored(&build.join("gen"), true));
+        assert!(!stack.is_ignored(&root.join("main.c"), false));
+    }
+}
@@ -1,6 +1,11 @@
-use crate::git;
-use crate::{CliArgs, Config};
+mod ignore_files;
+
+pub use ignore_files::{IgnoreStack, LINEGUARD_IGNORE_FILE};
+
+use crate::git::{self, ChangedLines};
+use crate::{CheckResult, CliArgs, Config};
 use glob::{Pattern, glob};
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, BufRead, BufReader};
 use std::path::{Component, Path, PathBuf};
@@ -14,10 +19,49 @@ pub struct GitRangeInfo {
     pub from: String,
     pub to: String,
     pub changed_files: Vec<PathBuf>,
+    /// Changed line ranges per absolute file path, set with `--changed-lines-only`
+    pub changed_lines: Option<HashMap<PathBuf, ChangedLines>>,
+}
+
+impl GitRangeInfo {
+    /// Drop issues outside the changed lines when `--changed-lines-only` is set
+    pub fn retain_changed_issues(&self, result: &mut CheckResult) {
+        let Some(changed_lines) = &self.changed_lines else {
+            return;
+        };
+        if result.issues.is_empty() {
+            return;
+        }
+
+        let abs_path = std::path::absolute(&result.file_path).unwrap_or_default();
+        let Some(lines) = changed_lines.get(&abs_path) else {
+            result.issues.clear();
+            return;
+        };
+
+        let last_line = if result.issues.iter().any(|issue| issue.line.is_none()) {
+            fs::read(&result.file_path)
+                .map(|content| count_lines(&content))
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        lines.retain_issues(&mut result.issues, last_line);
+    }
+}
+
+/// Number of lines in `content` as git counts them
+fn count_lines(content: &[u8]) -> usize {
+    let newlines = content.iter().filter(|&&b| b == b'\n').count();
+    if content.last().is_some_and(|&b| b != b'\n') {
+        newlines + 1
+    } else {
+        newlines
+    }
 }
 
 /// Pre-compile glob patterns once for efficient reuse.
-fn compile_ignore_patterns(patterns: &[String]) -> Vec<Pattern> {
+pub(crate) fn compile_patterns(patterns: &[String]) -> Vec<Pattern> {
     patterns
         .iter()
         .filter_map(|s| Pattern::new(s).ok())
@@ -45,9 +89,20 @@ pub fn discover_files(
     }
 
     // Pre-compile ignore patterns once for performance
-    let ignore_patterns = compile_ignore_patterns(&config.ignore_patterns);
+    let ignore_patterns = compile_patterns(&config.ignore_patterns);
 
-    if args.stdin {
+    if args.staged {
+        // Staged files, with the positional arguments used as pathspecs
+        let cwd = std::env::current_dir()?;
+        for path in git::get_staged_files(&cwd, &args.files)? {
+            if should_check_file(&path, &config)
+                && !is_ignored(&path, &ignore_patterns)
+                && !(args.no_hidden && is_hidden_file(&path))
+            {
+                files.push(path);
+            }
+        }
+    } else if args.stdin {
         // Read file paths from stdin
         let stdin = io::stdin();
         let reader = BufReader::new(stdin.lock());
@@ -74,6 +129,7 @@ pub fn discover_files(
 
             // Check if it's a directory
             if path.is_dir() {
+                let mut ignore_stack = IgnoreStack::new(&path, !args.no_ignore_vcs);
                 discover_files_in_dir(
                     &path,
                     args.recursive,
@@ -81,6 +137,7 @@ pub fn discover_files(
                     &config,
                     args.no_hidden,
                     &ignore_patterns,
+                    &mut ignore_stack,
                 )?;
             } else {
                 // Try glob pattern first
@@ -133,12 +190,22 @@ pub fn discover_files(
         // Get list of changed files from git
         let to_commit = args.to.as_deref().unwrap_or("HEAD");
         let changed_files = git::get_changed_files(from_commit, args.to.as_deref(), &cwd)?;
+        let changed_lines = if args.changed_lines_only {
+            Some(git::get_changed_lines(
+                from_commit,
+                args.to.as_deref(),
+                &cwd,
+            )?)
+        } else {
+            None
+        };
 
         // Store git range info
         git_range_info = Some(GitRangeInfo {
             from: from_commit.clone(),
             to: to_commit.to_string(),
             changed_files: changed_files.clone(),
+            changed_lines,
         });
 
         // Filter discovered files to only include changed files
@@ -174,6 +241,7 @@ fn discover_files_in_dir(
     config: &Config,
     no_hidden: bool,
     ignore_patterns: &[Pattern],
+    ignore_stack: &mut IgnoreStack,
 ) -> Result<(), anyhow::Error> {
     let entries = match fs::read_dir(dir) {
         Ok(entries) => entries,
@@ -182,6 +250,7 @@ fn discover_files_in_dir(
             return Ok(()); // Continue with other directories
         },
     };
+    ignore_stack.push_dir(dir);
 
     for entry in entries {
         let entry = match entry {
@@ -202,14 +271,30 @@ fn discover_files_in_dir(
             continue;
         }
 
-        if path.is_file() && should_check_file(&path, config) && !is_ignored(&path, ignore_patterns)
+        if path.is_file()
+            && should_check_file(&path, config)
+            && !is_ignored(&path, ignore_patterns)
+            && !ignore_stack.is_ignored(&path, false)
         {
             files.push(path);
-        } else if path.is_dir() && recursive && !is_ignored(&path, ignore_patterns) {
-            discover_files_in_dir(&path, recursive, files, config, no_hidden, ignore_patterns)?;
+        } else if path.is_dir()
+            && recursive
+            && !is_ignored(&path, ignore_patterns)
+            && !ignore_stack.is_ignored(&path, true)
+        {
+            discover_files_in_dir(
+                &path,
+                recursive,
+                files,
+                config,
+                no_hidden,
+                ignore_patterns,
+                ignore_stack,
+            )?;
         }
     }
 
+    ignore_stack.pop_dir();
     Ok(())
 }
 
@@ -222,6 +307,19 @@ fn is_hidden_file(path: &Path) -> bool {
     false
 }
 
+/// Extensions skipped as binary unless `binary_extensions` overrides the list
+pub const DEFAULT_BINARY_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "gif", "bmp", "ico", "icns", "cur", "svg", "webp", // Images
+    "mp3", "mp4", "avi", "mov", "wmv", "flv", "webm", // Audio/Video
+    "zip", "tar", "gz", "bz2", "xz", "7z", "rar", // Archives
+    "exe", "dll", "so", "dylib", "a", "o", "wasm", // Executables/Libraries
+    "bin", "dat", "db", "sqlite", // Binary data
+    "pdf", "doc", "docx", "xls", "xlsx", // Documents
+    "class", "jar", "war", // Java
+    "pyc", "pyo", // Python
+    "woff", "woff2", "ttf", "otf", "eot", // Fonts
+];
+
 pub fn should_check_file(path: &Path, config: &Config) -> bool {
     // Get file extension
     let extension = match path.extension() {
@@ -232,20 +330,8 @@ pub fn should_check_file(path: &Path, config: &Config) -> bool {
         None => return true, // No extension, check it
     };
 
-    // Skip common binary file extensions
-    const BINARY_EXTENSIONS: &[&str] = &[
-        "jpg", "jpeg", "png", "gif", "bmp", "ico", "svg", "webp", // Images
-        "mp3", "mp4", "avi", "mov", "wmv", "flv", "webm", // Audio/Video
-        "zip", "tar", "gz", "bz2", "xz", "7z", "rar", // Archives
-        "exe", "dll", "so", "dylib", "a", "o", // Executables/Libraries
-        "bin", "dat", "db", "sqlite", // Binary data
-        "pdf", "doc", "docx", "xls", "xlsx", // Documents
-        "class", "jar", "war", // Java
-        "pyc", "pyo", // Python
-        "woff", "woff2", "ttf", "otf", "eot", // Fonts
-    ];
-
-    if BINARY_EXTENSIONS.contains(&extension.as_str()) {
+    // Skip binary file extensions
+    if config.binary_detection.uses_extension() && is_binary_extension(&extension, config) {
         return false;
     }
 
@@ -257,6 +343,21 @@ pub fn should_check_file(path: &Path, config: &Config) -> bool {
     true
 }
 
+fn is_binary_extension(extension: &str, config: &Config) -> bool {
+    let listed = match &config.binary_extensions {
+        Some(extensions) => extensions
+            .iter()
+            .any(|ext| ext.eq_ignore_ascii_case(extension)),
+        None => DEFAULT_BINARY_EXTENSIONS.contains(&extension),
+    };
+
+    listed
+        || config
+            .extend_binary_extensions
+            .iter()
+            .any(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
 fn normalize_path(path: &Path) -> PathBuf {
     let mut components = Vec::new();
     for component in path.components() {
@@ -280,14 +381,19 @@ fn normalize_path(path: &Path) -> PathBuf {
 }
 
 fn is_ignored(path: &Path, ignore_patterns: &[Pattern]) -> bool {
-    if ignore_patterns.is_empty() {
+    matches_patterns(path, ignore_patterns)
+}
+
+/// Whether `path`, its file name or any of its parent directories matches a pattern
+pub(crate) fn matches_patterns(path: &Path, patterns: &[Pattern]) -> bool {
+    if patterns.is_empty() {
         return false;
     }
 
     let normalized_path_buf = normalize_path(path);
     let normalized_path = normalized_path_buf.as_path();
 
-    for pattern in ignore_patterns {
+    for pattern in patterns {
         // Check normalized relative path directly
         if pattern.matches_path(normalized_path) {
             return true;
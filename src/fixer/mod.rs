@@ -1,7 +1,11 @@
-use crate::config::Config;
+use crate::checker::{LineEnding, UTF8_BOM, hazards};
+use crate::config::{BomPolicy, Config, IndentStyle, LineEndingStyle};
 use crate::{Issue, IssueType};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs::{self, File};
-use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::ops::RangeInclusive;
 use std::path::Path;
 
 pub struct FixResult {
@@ -18,6 +22,7 @@ pub fn fix_file(
 ) -> Result<FixResult, anyhow::Error> {
     // Check file size
     let file_size = fs::metadata(path)?.len();
+    let config = &config.for_file(path);
 
     // Use streaming for files larger than 10MB
     if file_size > 10 * 1024 * 1024 {
@@ -33,8 +38,9 @@ fn fix_file_in_memory(
     config: &Config,
     dry_run: bool,
 ) -> Result<FixResult, anyhow::Error> {
-    let content = fs::read_to_string(path)?;
-    let fixed_content = apply_fixes(&content, issues, config);
+    // Work on raw bytes so legacy-encoded files are never re-encoded
+    let content = fs::read(path)?;
+    let fixed_content = apply_fixes(&content, issues, config)?;
 
     let fixed = content != fixed_content;
 
@@ -64,20 +70,8 @@ fn fix_file_streaming(
         });
     }
 
-    let has_trailing_spaces = config.checks.trailing_spaces
-        && issues
-            .iter()
-            .any(|i| i.issue_type == IssueType::TrailingSpace);
-
-    let has_newline_issues = config.checks.newline_ending
-        && issues.iter().any(|i| {
-            matches!(
-                i.issue_type,
-                IssueType::MissingNewline | IssueType::MultipleNewlines
-            )
-        });
-
-    if !has_trailing_spaces && !has_newline_issues {
+    let fixes = Fixes::new(issues, config);
+    if fixes.is_empty() {
         return Ok(FixResult {
             file_path: path.to_path_buf(),
             fixed: false,
@@ -89,44 +83,9 @@ fn fix_file_streaming(
     let temp_path = path.with_extension("tmp");
 
     {
-        let input_file = File::open(path)?;
-        let output_file = File::create(&temp_path)?;
-        let reader = BufReader::new(input_file);
-        let mut writer = BufWriter::new(output_file);
-
-        let mut lines: Vec<String> = Vec::new();
-
-        // Process lines
-        for line_result in reader.lines() {
-            let line = line_result?;
-            if has_trailing_spaces {
-                lines.push(line.trim_end().to_string());
-            } else {
-                lines.push(line);
-            }
-        }
-
-        // Fix newline ending if needed
-        if has_newline_issues && !lines.is_empty() {
-            // Remove empty lines at the end
-            while lines.len() > 1 && lines.last().is_some_and(|l| l.is_empty()) {
-                lines.pop();
-            }
-        }
-
-        // Write all lines
-        for (i, line) in lines.iter().enumerate() {
-            if i > 0 {
-                writeln!(writer)?;
-            }
-            write!(writer, "{line}")?;
-        }
-
-        // Ensure file ends with newline
-        if has_newline_issues || !lines.is_empty() {
-            writeln!(writer)?;
-        }
-
+        let reader = BufReader::new(File::open(path)?);
+        let mut writer = BufWriter::new(File::create(&temp_path)?);
+        fixes.write(reader, &mut writer)?;
         writer.flush()?;
     }
 
@@ -140,96 +99,906 @@ fn fix_file_streaming(
     })
 }
 
-fn apply_fixes(content: &str, issues: &[Issue], config: &Config) -> String {
-    let mut result = content.to_string();
+fn apply_fixes(content: &[u8], issues: &[Issue], config: &Config) -> io::Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(content.len());
+    Fixes::new(issues, config).write(content, &mut result)?;
+    Ok(result)
+}
+
+/// The fixes for a file's issues
+///
+/// Line fixes apply only to the lines their issues were reported on, so lines
+/// left out of the issues (unchanged lines under `--changed-lines-only`,
+/// baselined or suppressed issues) stay byte-for-byte the same. An issue
+/// without a line applies its fix to every line. Lines are numbered as in the
+/// original file throughout.
+struct Fixes<'a> {
+    config: &'a Config,
+    /// Issues reported on each 1-based line
+    lines: HashMap<usize, Vec<IssueType>>,
+    /// Issues reported without a line, which apply to the whole file
+    whole_file: Vec<IssueType>,
+    /// Blank lines to remove
+    removals: Vec<RangeInclusive<usize>>,
+    line_ending: Option<LineEndingStyle>,
+    indent_style: Option<IndentStyle>,
+    fix_controls: bool,
+    trim: Trim<'a>,
+    /// Whether the end of the file needs exactly one newline
+    newline: bool,
+    bom: Option<BomPolicy>,
+}
+
+impl<'a> Fixes<'a> {
+    fn new(issues: &[Issue], config: &'a Config) -> Self {
+        let mut lines: HashMap<usize, Vec<IssueType>> = HashMap::new();
+        let mut whole_file = Vec::new();
+        for issue in issues {
+            match issue.line {
+                Some(line) => lines.entry(line).or_default().push(issue.issue_type),
+                None => whole_file.push(issue.issue_type),
+            }
+        }
+
+        Fixes {
+            config,
+            lines,
+            whole_file,
+            removals: blank_line_removals(issues, config),
+            line_ending: config
+                .checks
+                .line_ending
+                .filter(|_| has_line_ending_issues(issues)),
+            indent_style: indentation_fix(issues, config),
+            fix_controls: control_character_fix(issues, config),
+            trim: Trim::for_issues(issues, config),
+            newline: config.checks.newline_ending
+                && issues.iter().any(|i| {
+                    matches!(
+                        i.issue_type,
+                        IssueType::MissingNewline | IssueType::MultipleNewlines
+                    )
+                }),
+            bom: bom_fix(issues, config),
+        }
+    }
 
-    // Fix trailing spaces if enabled
-    if config.checks.trailing_spaces
+    fn is_empty(&self) -> bool {
+        self.removals.is_empty()
+            && self.line_ending.is_none()
+            && self.indent_style.is_none()
+            && !self.fix_controls
+            && !self.trim.any()
+            && !self.newline
+            && self.bom.is_none()
+    }
+
+    /// Copy `reader` to `writer` with the fixes applied
+    fn write(&self, mut reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+        let mut raw = Vec::new();
+        let mut line_number = 0;
+        let mut file_ending = LineEnding::Lf;
+        let mut target = None;
+        // Terminators of empty lines held back, as they may end the file
+        let mut pending: Vec<LineEnding> = Vec::new();
+        // Whether the last line written has a terminator
+        let mut terminated = None;
+
+        while reader.read_until(b'\n', &mut raw)? > 0 {
+            line_number += 1;
+            if line_number == 1 {
+                file_ending = LineEnding::detect_bytes(&raw).unwrap_or(LineEnding::Lf);
+                target = self
+                    .line_ending
+                    .map(|style| target_line_ending(style, file_ending));
+                if let Some(policy) = self.bom {
+                    raw = fix_bom(&raw, policy);
+                }
+            }
+
+            if self
+                .removals
+                .iter()
+                .any(|range| range.contains(&line_number))
+            {
+                // A removed first line keeps the byte order mark it started with
+                if line_number == 1 && raw.starts_with(UTF8_BOM) {
+                    writer.write_all(UTF8_BOM)?;
+                }
+            } else {
+                for (body, ending) in self.fix_line(line_number, &raw, target) {
+                    if self.newline && body.is_empty() {
+                        pending.extend(ending);
+                        continue;
+                    }
+                    for ending in pending.drain(..) {
+                        writer.write_all(ending.as_bytes())?;
+                    }
+                    writer.write_all(&body)?;
+                    if let Some(ending) = ending {
+                        writer.write_all(ending.as_bytes())?;
+                    }
+                    terminated = Some(ending.is_some());
+                }
+            }
+            raw.clear();
+        }
+
+        // Trailing empty lines are dropped, and the last line terminated
+        if self.newline
+            && (terminated == Some(false) || terminated.is_none() && !pending.is_empty())
+        {
+            writer.write_all(target.unwrap_or(file_ending).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Raw line `line_number`, split into its fixed content and terminator,
+    /// with the fixes for the issues reported on it
+    fn fix_line(
+        &self,
+        line_number: usize,
+        raw: &[u8],
+        target: Option<LineEnding>,
+    ) -> Vec<(Vec<u8>, Option<LineEnding>)> {
+        let issues = self.lines.get(&line_number).map_or(&[][..], Vec::as_slice);
+        let has =
+            |issue_type| issues.contains(&issue_type) || self.whole_file.contains(&issue_type);
+        let checks = &self.config.checks;
+
+        let mut raw = Cow::Borrowed(raw);
+        if let Some(style) = self.indent_style
+            && INDENTATION_ISSUES.iter().any(|&issue_type| has(issue_type))
+            && let Some(fixed) = reindent_line(&raw, style, checks.tab_width, checks.indent_size)
+        {
+            raw = Cow::Owned(fixed);
+        }
+        if self.fix_controls && has(IssueType::ControlCharacter) {
+            raw = Cow::Owned(remove_control_characters(&raw, self.config));
+        }
+
+        let target =
+            target.filter(|_| LINE_ENDING_ISSUES.iter().any(|&issue_type| has(issue_type)));
+        let trim = Trim {
+            after_content: self.trim.after_content && has(IssueType::TrailingSpace),
+            whitespace_only: self.trim.whitespace_only && has(IssueType::WhitespaceOnlyLine),
+            ..self.trim
+        };
+        split_line(&raw, target, trim)
+            .into_iter()
+            .map(|(body, ending)| (body.to_vec(), ending))
+            .collect()
+    }
+}
+
+/// Whether reported control characters should be deleted
+fn control_character_fix(issues: &[Issue], config: &Config) -> bool {
+    config.checks.fix_control_characters
         && issues
             .iter()
-            .any(|i| i.issue_type == IssueType::TrailingSpace)
-    {
-        result = fix_trailing_spaces(&result);
+            .any(|i| i.issue_type == IssueType::ControlCharacter)
+}
+
+/// A raw line, terminator included, without its stray control characters
+///
+/// A stray escape is removed with the rest of its ANSI escape sequence, so no
+/// `[31m` is left behind. Lines that are not valid UTF-8 only have ASCII
+/// controls removed, as their other bytes belong to a legacy encoding.
+fn remove_control_characters(raw: &[u8], config: &Config) -> Vec<u8> {
+    let checks = &config.checks;
+    let report_cr = checks.line_ending.is_none();
+    let is_stray =
+        |c: char| hazards::is_stray_control(c, &checks.allowed_control_characters, report_cr);
+
+    let body = LineEnding::strip_bytes(raw);
+    let text = std::str::from_utf8(body).ok();
+    let mut result = Vec::with_capacity(raw.len());
+    let mut i = 0;
+    while i < body.len() {
+        let (c, len) = match text {
+            Some(text) => {
+                let c = text[i..].chars().next().unwrap_or_default();
+                (Some(c), c.len_utf8())
+            },
+            None => (body[i].is_ascii().then(|| char::from(body[i])), 1),
+        };
+        match c {
+            Some('\x1b') if is_stray('\x1b') => i += escape_sequence_len(&body[i..]),
+            Some(c) if is_stray(c) => i += len,
+            _ => {
+                result.extend_from_slice(&body[i..i + len]);
+                i += len;
+            },
+        }
     }
+    result.extend_from_slice(&raw[body.len()..]);
+    result
+}
 
-    // Fix newline issues if enabled
-    if config.checks.newline_ending {
-        let has_missing_newline = issues
+/// The length of the escape sequence at the start of `bytes`, which begins
+/// with ESC: a whole CSI (`ESC [`) or OSC (`ESC ]`) sequence, or the escape
+/// alone if it starts neither or is not terminated
+fn escape_sequence_len(bytes: &[u8]) -> usize {
+    match bytes.get(1) {
+        // Parameter and intermediate bytes, then a final byte
+        Some(b'[') => bytes[2..]
             .iter()
-            .any(|i| i.issue_type == IssueType::MissingNewline);
-        let has_multiple_newlines = issues
+            .position(|b| !(0x20..=0x3f).contains(b))
+            .filter(|&n| (0x40..=0x7e).contains(&bytes[2 + n]))
+            .map_or(1, |n| n + 3),
+        // Terminated by BEL or by ESC \
+        Some(b']') => (2..bytes.len())
+            .find_map(|n| match bytes[n] {
+                0x07 => Some(n + 1),
+                0x1b if bytes.get(n + 1) == Some(&b'\\') => Some(n + 2),
+                _ => None,
+            })
+            .unwrap_or(1),
+        _ => 1,
+    }
+}
+
+/// 1-based line ranges to remove to fix blank line issues
+fn blank_line_removals(issues: &[Issue], config: &Config) -> Vec<RangeInclusive<usize>> {
+    issues
+        .iter()
+        .filter_map(|issue| {
+            let start = issue.line?;
+            let end = issue.end_line.unwrap_or(start);
+            match issue.issue_type {
+                IssueType::LeadingBlankLines => Some(start..=end),
+                // Keep the first lines of the run, up to the maximum
+                IssueType::ConsecutiveBlankLines => config
+                    .checks
+                    .max_consecutive_blank_lines
+                    .map(|max| start + max..=end),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// The byte order mark policy to enforce, if a BOM issue was found
+fn bom_fix(issues: &[Issue], config: &Config) -> Option<BomPolicy> {
+    issues
+        .iter()
+        .any(|i| {
+            matches!(
+                i.issue_type,
+                IssueType::UnexpectedBom | IssueType::MissingBom
+            )
+        })
+        .then_some(config.checks.bom)
+}
+
+/// Strip or insert a UTF-8 byte order mark at the start of `content`
+fn fix_bom(content: &[u8], policy: BomPolicy) -> Vec<u8> {
+    let has_bom = content.starts_with(UTF8_BOM);
+    match policy {
+        BomPolicy::Forbid if has_bom => content[UTF8_BOM.len()..].to_vec(),
+        BomPolicy::Require if !has_bom => [UTF8_BOM, content].concat(),
+        _ => content.to_vec(),
+    }
+}
+
+/// Issues fixed by normalising line endings
+const LINE_ENDING_ISSUES: &[IssueType] = &[
+    IssueType::CrlfLineEnding,
+    IssueType::LfLineEnding,
+    IssueType::LoneCarriageReturn,
+    IssueType::MixedLineEndings,
+];
+
+fn has_line_ending_issues(issues: &[Issue]) -> bool {
+    issues
+        .iter()
+        .any(|i| LINE_ENDING_ISSUES.contains(&i.issue_type))
+}
+
+/// Issues fixed by re-indenting lines
+const INDENTATION_ISSUES: &[IssueType] = &[
+    IssueType::TabIndentation,
+    IssueType::SpaceIndentation,
+    IssueType::MixedIndentation,
+];
+
+/// The style to re-indent lines to, if there are indentation issues and
+/// `indent_style` is configured
+fn indentation_fix(issues: &[Issue], config: &Config) -> Option<IndentStyle> {
+    config.checks.indent_style.filter(|_| {
+        issues
             .iter()
-            .any(|i| i.issue_type == IssueType::MultipleNewlines);
+            .any(|i| INDENTATION_ISSUES.contains(&i.issue_type))
+    })
+}
 
-        if has_missing_newline || has_multiple_newlines {
-            result = fix_newline_ending(&result);
-        }
+/// Rewrite the leading whitespace of a raw line in `style`, keeping its width
+///
+/// Tabs advance to the next multiple of `tab_width`. In the `tabs` style, each
+/// full level of `indent_size` columns becomes a tab, and the width left over
+/// stays as alignment spaces. Returns `None` when the line is already
+/// indented in `style` or holds only whitespace.
+fn reindent_line(
+    raw: &[u8],
+    style: IndentStyle,
+    tab_width: usize,
+    indent_size: usize,
+) -> Option<Vec<u8>> {
+    let tab_width = tab_width.max(1);
+    let indent_size = indent_size.max(1);
+    let indent_len = raw
+        .iter()
+        .position(|&b| b != b' ' && b != b'\t')
+        .unwrap_or(raw.len());
+    let rest = &raw[indent_len..];
+    if LineEnding::strip_bytes(rest).is_empty() {
+        return None;
     }
 
-    result
+    let width = raw[..indent_len].iter().fold(0, |width, &b| match b {
+        b'\t' => (width / tab_width + 1) * tab_width,
+        _ => width + 1,
+    });
+    let mut fixed = match style {
+        IndentStyle::Spaces => vec![b' '; width],
+        IndentStyle::Tabs => {
+            let mut indent = vec![b'\t'; width / indent_size];
+            indent.resize(indent.len() + width % indent_size, b' ');
+            indent
+        },
+    };
+
+    if fixed == raw[..indent_len] {
+        return None;
+    }
+    fixed.extend_from_slice(rest);
+    Some(fixed)
+}
+
+/// Resolve the line ending to normalise to for the configured style
+fn target_line_ending(style: LineEndingStyle, file_ending: LineEnding) -> LineEnding {
+    match style.resolve() {
+        LineEndingStyle::Lf => LineEnding::Lf,
+        LineEndingStyle::Crlf => LineEnding::Crlf,
+        _ => file_ending,
+    }
 }
 
-fn fix_trailing_spaces(content: &str) -> String {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut result = String::new();
+/// Trim trailing whitespace other than the characters in `keep` from a line
+/// without its terminator
+///
+/// UTF-8 lines are trimmed like `str::trim_end`. Lines in other encodings only
+/// lose trailing ASCII whitespace, so bytes of multi-byte characters are kept.
+fn trim_line_end<'a>(line: &'a [u8], keep: &[char]) -> &'a [u8] {
+    let is_trimmed = |c: char| c.is_whitespace() && !keep.contains(&c);
+    match std::str::from_utf8(line) {
+        Ok(line) => line.trim_end_matches(is_trimmed).as_bytes(),
+        Err(_) => {
+            let len = line
+                .iter()
+                .rposition(|&b| !(b.is_ascii() && is_trimmed(char::from(b))))
+                .map_or(0, |pos| pos + 1);
+            &line[..len]
+        },
+    }
+}
+
+/// Which trailing whitespace to remove from each line
+#[derive(Debug, Clone, Copy, Default)]
+struct Trim<'a> {
+    /// Whitespace after the content of a line
+    after_content: bool,
+    /// Lines holding nothing but whitespace
+    whitespace_only: bool,
+    /// Keep exactly two trailing spaces, a Markdown hard break
+    keep_hard_breaks: bool,
+    /// Allowed control characters, which are kept even though they are
+    /// whitespace
+    keep: &'a [char],
+}
 
-    for (i, line) in lines.iter().enumerate() {
-        result.push_str(line.trim_end());
-        if i < lines.len() - 1 {
-            result.push('\n');
+impl<'a> Trim<'a> {
+    /// The trimming needed to fix the whitespace issues in `issues`
+    fn for_issues(issues: &[Issue], config: &'a Config) -> Trim<'a> {
+        let has = |issue_type| issues.iter().any(|i| i.issue_type == issue_type);
+        Trim {
+            after_content: config.checks.trailing_spaces && has(IssueType::TrailingSpace),
+            whitespace_only: config.checks.reports_whitespace_only_lines()
+                && has(IssueType::WhitespaceOnlyLine),
+            keep_hard_breaks: config.checks.markdown_hard_breaks,
+            keep: &config.checks.allowed_control_characters,
         }
     }
 
-    // Preserve final newline if original had one
-    if content.ends_with('\n') {
-        result.push('\n');
+    fn any(self) -> bool {
+        self.after_content || self.whitespace_only
     }
 
-    result
+    /// `line` (without its terminator) with the selected whitespace removed
+    fn apply(self, line: &[u8]) -> &[u8] {
+        let trimmed = trim_line_end(line, self.keep);
+        let trim = if trimmed.is_empty() {
+            self.whitespace_only
+        } else {
+            self.after_content && !(self.keep_hard_breaks && &line[trimmed.len()..] == b"  ")
+        };
+        if trim { trimmed } else { line }
+    }
 }
 
-fn fix_newline_ending(content: &str) -> String {
-    let mut result = content.trim_end().to_string();
-    result.push('\n');
-    result
+/// Split a raw line into its fixed content and terminator
+///
+/// With a target line ending, the terminator is converted and lone CRs become
+/// line breaks, so one line may turn into several. Otherwise the line keeps its
+/// own terminator, so CRLF files stay CRLF.
+fn split_line<'a>(
+    raw: &'a [u8],
+    target: Option<LineEnding>,
+    trim: Trim<'_>,
+) -> Vec<(&'a [u8], Option<LineEnding>)> {
+    let ending = LineEnding::detect_bytes(raw);
+    let body = LineEnding::strip_bytes(raw);
+
+    let mut pieces = match target {
+        Some(target) => {
+            let mut pieces = Vec::new();
+            let mut split = body.split(|&b| b == b'\r').peekable();
+            while let Some(piece) = split.next() {
+                let piece_ending = if split.peek().is_some() {
+                    Some(target)
+                } else {
+                    ending.map(|_| target)
+                };
+                pieces.push((piece, piece_ending));
+            }
+            pieces
+        },
+        None => vec![(body, ending)],
+    };
+
+    if trim.any() {
+        for (body, _) in &mut pieces {
+            *body = trim.apply(body);
+        }
+    }
+
+    pieces
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Severity;
+
+    fn split_lines(content: &[u8], target: Option<LineEnding>, trim: Trim<'_>) -> Vec<u8> {
+        let mut result = Vec::new();
+        for raw in content.split_inclusive(|&b| b == b'\n') {
+            for (body, ending) in split_line(raw, target, trim) {
+                result.extend_from_slice(body);
+                result.extend_from_slice(ending.map_or(&[][..], LineEnding::as_bytes));
+            }
+        }
+        result
+    }
+
+    fn fix_trailing_spaces(content: &[u8]) -> Vec<u8> {
+        let trim = Trim {
+            after_content: true,
+            whitespace_only: true,
+            keep_hard_breaks: false,
+            keep: &[],
+        };
+        split_lines(content, None, trim)
+    }
+
+    fn normalize_line_endings(content: &[u8], target: LineEnding) -> Vec<u8> {
+        split_lines(content, Some(target), Trim::default())
+    }
+
+    fn fix_newline_ending(content: &[u8]) -> Vec<u8> {
+        let issue = Issue {
+            issue_type: IssueType::MissingNewline,
+            line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: String::new(),
+        };
+        apply_fixes(content, &[issue], &Config::default()).unwrap()
+    }
 
     #[test]
     fn test_fix_trailing_spaces_only() {
-        let content = "line 1  \nline 2   \nline 3\n";
-        let expected = "line 1\nline 2\nline 3\n";
+        let content = b"line 1  \nline 2   \nline 3\n";
+        let expected = b"line 1\nline 2\nline 3\n";
         assert_eq!(fix_trailing_spaces(content), expected);
     }
 
     #[test]
     fn test_fix_trailing_spaces_no_final_newline() {
-        let content = "line 1  \nline 2   ";
-        let expected = "line 1\nline 2";
+        let content = b"line 1  \nline 2   ";
+        let expected = b"line 1\nline 2";
         assert_eq!(fix_trailing_spaces(content), expected);
     }
 
     #[test]
     fn test_fix_newline_missing() {
-        let content = "line 1\nline 2";
-        let expected = "line 1\nline 2\n";
+        let content = b"line 1\nline 2";
+        let expected = b"line 1\nline 2\n";
         assert_eq!(fix_newline_ending(content), expected);
     }
 
     #[test]
     fn test_fix_newline_multiple() {
-        let content = "line 1\nline 2\n\n\n";
-        let expected = "line 1\nline 2\n";
+        let content = b"line 1\nline 2\n\n\n";
+        let expected = b"line 1\nline 2\n";
         assert_eq!(fix_newline_ending(content), expected);
     }
 
     #[test]
     fn test_fix_newline_already_correct() {
-        let content = "line 1\nline 2\n";
-        let expected = "line 1\nline 2\n";
+        let content = b"line 1\nline 2\n";
+        let expected = b"line 1\nline 2\n";
         assert_eq!(fix_newline_ending(content), expected);
     }
+
+    #[test]
+    fn test_fix_trailing_spaces_preserves_crlf() {
+        let content = b"line 1  \r\nline 2\t\r\nline 3\n";
+        let expected = b"line 1\r\nline 2\r\nline 3\n";
+        assert_eq!(fix_trailing_spaces(content), expected);
+    }
+
+    #[test]
+    fn test_fix_newline_preserves_crlf() {
+        assert_eq!(
+            fix_newline_ending(b"line 1\r\nline 2"),
+            b"line 1\r\nline 2\r\n"
+        );
+        assert_eq!(
+            fix_newline_ending(b"line 1\r\nline 2\r\n\r\n"),
+            b"line 1\r\nline 2\r\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_trailing_spaces_unicode_whitespace() {
+        let content = "full-width space\u{3000}\n";
+        assert_eq!(
+            fix_trailing_spaces(content.as_bytes()),
+            b"full-width space\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_legacy_encoding_is_not_reencoded() {
+        // Shift-JIS "ア" is 0x83 0x41 and Latin-1 "é" is 0xE9; NBSP (0xA0) is a
+        // valid Shift-JIS trail byte and must not be trimmed as whitespace
+        let content = b"\x83\x41  \ncaf\xe9\t\n\x88\xa0";
+        assert_eq!(fix_trailing_spaces(content), b"\x83\x41\ncaf\xe9\n\x88\xa0");
+        assert_eq!(
+            fix_newline_ending(content),
+            b"\x83\x41  \ncaf\xe9\t\n\x88\xa0\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_line_endings() {
+        let content = b"a\r\nb\nc\rd";
+        assert_eq!(
+            normalize_line_endings(content, LineEnding::Lf),
+            b"a\nb\nc\nd"
+        );
+        assert_eq!(
+            normalize_line_endings(content, LineEnding::Crlf),
+            b"a\r\nb\r\nc\r\nd"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_preserves_endings_without_line_ending_check() {
+        let content = b"a  \r\nb\r\n";
+        let issues = vec![Issue {
+            issue_type: IssueType::TrailingSpace,
+            line: Some(1),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: "Trailing spaces found".to_string(),
+        }];
+        assert_eq!(
+            apply_fixes(content, &issues, &Config::default()).unwrap(),
+            b"a\r\nb\r\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_normalizes_to_configured_ending() {
+        let mut config = Config::default();
+        config.checks.line_ending = Some(LineEndingStyle::Crlf);
+        let issues = vec![Issue {
+            issue_type: IssueType::LfLineEnding,
+            line: Some(2),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: "LF line ending found (expected CRLF)".to_string(),
+        }];
+        assert_eq!(
+            apply_fixes(b"a\r\nb\nc\r\n", &issues, &config).unwrap(),
+            b"a\r\nb\r\nc\r\n"
+        );
+
+        // Consistent style normalises to the file's first line ending
+        config.checks.line_ending = Some(LineEndingStyle::Consistent);
+        let issues: Vec<_> = [2, 3]
+            .into_iter()
+            .map(|line| Issue {
+                issue_type: IssueType::MixedLineEndings,
+                line: Some(line),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "Mixed line endings: LF found in a file using CRLF".to_string(),
+            })
+            .collect();
+        assert_eq!(
+            apply_fixes(b"a\r\nb\nc\n", &issues, &config).unwrap(),
+            b"a\r\nb\r\nc\r\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_only_touches_reported_lines() {
+        let mut config = Config::default();
+        let issue = |issue_type, line| Issue {
+            issue_type,
+            line: Some(line),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: String::new(),
+        };
+
+        // Lines left out of the issues, such as unchanged lines under
+        // `--changed-lines-only`, stay byte-identical
+        let issues = vec![issue(IssueType::TrailingSpace, 3)];
+        assert_eq!(
+            apply_fixes(b"old  \nx\nnew  \n", &issues, &config).unwrap(),
+            b"old  \nx\nnew\n"
+        );
+
+        // Issues without a line apply to the whole file
+        let mut whole_file = issue(IssueType::TrailingSpace, 1);
+        whole_file.line = None;
+        assert_eq!(
+            apply_fixes(b"old  \nx\nnew  \n", &[whole_file], &config).unwrap(),
+            b"old\nx\nnew\n"
+        );
+
+        config.checks.line_ending = Some(LineEndingStyle::Lf);
+        config.checks.fix_control_characters = true;
+        let issues = vec![
+            issue(IssueType::CrlfLineEnding, 2),
+            issue(IssueType::ControlCharacter, 2),
+            issue(IssueType::WhitespaceOnlyLine, 2),
+        ];
+        assert_eq!(
+            apply_fixes(b"a\x07 \r\n \x07\r\n  \r\n", &issues, &config).unwrap(),
+            b"a\x07 \r\n\n  \r\n"
+        );
+
+        // The end of the file keeps the whitespace of its last line
+        let issues = vec![issue(IssueType::MultipleNewlines, 1)];
+        assert_eq!(
+            apply_fixes(b"a  \r\n\r\n\r\n", &issues, &config).unwrap(),
+            b"a  \r\n"
+        );
+    }
+
+    #[test]
+    fn test_reindent_line() {
+        assert_eq!(
+            reindent_line(b"\t\tx\n", IndentStyle::Spaces, 4, 4),
+            Some(b"        x\n".to_vec())
+        );
+        // A space before a tab still reaches the next tab stop
+        assert_eq!(
+            reindent_line(b" \tx", IndentStyle::Spaces, 2, 2),
+            Some(b"  x".to_vec())
+        );
+        assert_eq!(
+            reindent_line(b"          x\r\n", IndentStyle::Tabs, 4, 4),
+            Some(b"\t\t  x\r\n".to_vec())
+        );
+        assert_eq!(reindent_line(b"\t  x", IndentStyle::Tabs, 4, 4), None);
+        assert_eq!(reindent_line(b"    x", IndentStyle::Spaces, 4, 4), None);
+        assert_eq!(reindent_line(b"\t \n", IndentStyle::Spaces, 4, 4), None);
+
+        // Tab stops follow `tab_width`, levels `indent_size`
+        assert_eq!(
+            reindent_line(b"\tx", IndentStyle::Spaces, 8, 2),
+            Some(b"        x".to_vec())
+        );
+        assert_eq!(
+            reindent_line(b"     x", IndentStyle::Tabs, 8, 2),
+            Some(b"\t\t x".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_reindents() {
+        let mut config = Config::default();
+        config.checks.indent_style = Some(IndentStyle::Tabs);
+        let issues = vec![Issue {
+            issue_type: IssueType::SpaceIndentation,
+            line: Some(2),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: "Space indentation found (expected tabs)".to_string(),
+        }];
+        let content = b"all:\n    build  \n# lineguard-disable-next-line\n    keep\n";
+        assert_eq!(
+            apply_fixes(content, &issues, &config).unwrap(),
+            b"all:\n\tbuild  \n# lineguard-disable-next-line\n    keep\n"
+        );
+
+        // Without a configured style there is nothing to convert to
+        config.checks.indent_style = None;
+        assert_eq!(apply_fixes(content, &issues, &config).unwrap(), content);
+    }
+
+    #[test]
+    fn test_apply_fixes_byte_order_mark() {
+        let mut config = Config::default();
+        let issue = |issue_type| Issue {
+            issue_type,
+            line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: String::new(),
+        };
+
+        config.checks.bom = BomPolicy::Forbid;
+        assert_eq!(
+            apply_fixes(
+                b"\xEF\xBB\xBFtext\n",
+                &[issue(IssueType::UnexpectedBom)],
+                &config
+            )
+            .unwrap(),
+            b"text\n"
+        );
+
+        config.checks.bom = BomPolicy::Require;
+        assert_eq!(
+            apply_fixes(b"text\n", &[issue(IssueType::MissingBom)], &config).unwrap(),
+            b"\xEF\xBB\xBFtext\n"
+        );
+
+        // Without a BOM issue the content is left alone
+        assert_eq!(apply_fixes(b"text\n", &[], &config).unwrap(), b"text\n");
+    }
+
+    #[test]
+    fn test_apply_fixes_blank_lines() {
+        let mut config = Config::default();
+        config.checks.max_consecutive_blank_lines = Some(1);
+        config.checks.leading_blank_lines = true;
+        let issue = |issue_type, line, end_line| Issue {
+            issue_type,
+            line: Some(line),
+            end_line,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
+            message: String::new(),
+        };
+        let issues = vec![
+            issue(IssueType::LeadingBlankLines, 1, Some(2)),
+            issue(IssueType::ConsecutiveBlankLines, 4, Some(6)),
+            issue(IssueType::ConsecutiveBlankLines, 8, Some(9)),
+        ];
+
+        // Only the reported runs change, down to their line endings
+        let content = b"\n\r\na \r\n\n \n\t\nb\n\r\n\r\nc\n\n\nd";
+        assert_eq!(
+            apply_fixes(content, &issues, &config).unwrap(),
+            b"a \r\n\nb\n\r\nc\n\n\nd"
+        );
+
+        // A removed first line keeps its byte order mark
+        config.checks.bom = BomPolicy::Ignore;
+        let issues = vec![issue(IssueType::LeadingBlankLines, 1, Some(2))];
+        assert_eq!(
+            apply_fixes(b"\xEF\xBB\xBF\n\n\tfoo\n", &issues, &config).unwrap(),
+            b"\xEF\xBB\xBF\tfoo\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_whitespace_only_and_hard_breaks() {
+        let mut config = Config::default();
+        config.checks.markdown_hard_breaks = true;
+        let issues = |issue_type, lines: &[usize]| -> Vec<Issue> {
+            lines
+                .iter()
+                .map(|&line| Issue {
+                    issue_type,
+                    line: Some(line),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
+                    message: String::new(),
+                })
+                .collect()
+        };
+        let content = b"one \ntwo  \nthree   \n  \n";
+
+        // Hard breaks are kept, and whitespace-only lines left to their own rule
+        assert_eq!(
+            apply_fixes(
+                content,
+                &issues(IssueType::TrailingSpace, &[1, 2, 3, 4]),
+                &config
+            )
+            .unwrap(),
+            b"one\ntwo  \nthree\n  \n"
+        );
+        assert_eq!(
+            apply_fixes(
+                content,
+                &issues(IssueType::WhitespaceOnlyLine, &[4]),
+                &config
+            )
+            .unwrap(),
+            b"one \ntwo  \nthree   \n\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_control_characters() {
+        let mut config = Config::default();
+        let issues: Vec<_> = (1..=3)
+            .map(|line| Issue {
+                issue_type: IssueType::ControlCharacter,
+                line: Some(line),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: String::new(),
+            })
+            .collect();
+        let content = b"\x1b[31mred\x1b[0m\r\nc\rd\x0c\r\ncaf\xe9\x07\r\n";
+
+        // Deleting is opt-in
+        assert_eq!(apply_fixes(content, &issues, &config).unwrap(), content);
+
+        // Escape sequences go as a whole; form feeds are allowed by default
+        config.checks.fix_control_characters = true;
+        assert_eq!(
+            apply_fixes(content, &issues, &config).unwrap(),
+            b"red\r\ncd\x0c\r\ncaf\xe9\r\n"
+        );
+
+        let content = b"\x1b]8;;https://example.com\x07link\x1b]8;;\x1b\\ \x1b[\x1bx\n";
+        assert_eq!(
+            apply_fixes(content, &issues, &config).unwrap(),
+            b"link [x\n"
+        );
+
+        config.checks.allowed_control_characters = Vec::new();
+        assert_eq!(apply_fixes(b"a\x0cb\n", &issues, &config).unwrap(), b"ab\n");
+    }
 }
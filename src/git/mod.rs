@@ -1,6 +1,31 @@
+use crate::checker::{FileMetadata, FileReader, Issue};
 use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+/// Line ranges added or modified in a file, taken from `git diff -U0` hunks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChangedLines {
+    /// Inclusive `(start, end)` line ranges in the new version of the file
+    pub ranges: Vec<(usize, usize)>,
+}
+
+impl ChangedLines {
+    pub fn contains(&self, line: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(start, end)| start <= line && line <= end)
+    }
+
+    /// Keep only issues on changed lines. File-level issues (those without a
+    /// line number) are kept only when `last_line` itself was changed.
+    pub fn retain_issues(&self, issues: &mut Vec<Issue>, last_line: usize) {
+        issues.retain(|issue| self.contains(issue.line.unwrap_or(last_line)));
+    }
+}
 
 /// Get list of files changed between two commits
 pub fn get_changed_files(from: &str, to: Option<&str>, repo_path: &Path) -> Result<Vec<PathBuf>> {
@@ -36,6 +61,309 @@ pub fn get_changed_files(from: &str, to: Option<&str>, repo_path: &Path) -> Resu
     Ok(files)
 }
 
+/// Get the lines added or modified in each file between two commits
+pub fn get_changed_lines(
+    from: &str,
+    to: Option<&str>,
+    repo_path: &Path,
+) -> Result<HashMap<PathBuf, ChangedLines>> {
+    if !is_git_repository(repo_path)? {
+        return Err(anyhow!("not a git repository"));
+    }
+
+    let from_hash = resolve_commit_hash(from, repo_path)?;
+    let to_hash = resolve_commit_hash(to.unwrap_or("HEAD"), repo_path)?;
+
+    let output = Command::new("git")
+        .args([
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "-U0",
+            "--no-color",
+            "--no-ext-diff",
+            // parse_diff_hunks expects these whatever diff.noprefix says
+            "--src-prefix=a/",
+            "--dst-prefix=b/",
+            &from_hash,
+            &to_hash,
+        ])
+        .current_dir(repo_path)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to get changed lines: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(parse_diff_hunks(&String::from_utf8_lossy(&output.stdout))
+        .into_iter()
+        .map(|(path, lines)| (repo_path.join(path), lines))
+        .collect())
+}
+
+/// Parse unified diff output into the changed line ranges of each new file
+pub fn parse_diff_hunks(diff: &str) -> HashMap<PathBuf, ChangedLines> {
+    let mut changes: HashMap<PathBuf, ChangedLines> = HashMap::new();
+    let mut current: Option<PathBuf> = None;
+    // Added lines starting with "++ " look like file headers inside a hunk
+    let mut in_header = false;
+
+    for line in diff.lines() {
+        if line.starts_with("diff ") {
+            in_header = true;
+            current = None;
+        } else if line.starts_with("@@ ") {
+            in_header = false;
+            if let Some(path) = &current
+                && let Some((start, count)) = parse_hunk_header(line)
+                && count > 0
+            {
+                changes
+                    .entry(path.clone())
+                    .or_default()
+                    .ranges
+                    .push((start, start + count - 1));
+            }
+        } else if in_header && let Some(target) = line.strip_prefix("+++ ") {
+            current = target.strip_prefix("b/").map(PathBuf::from);
+            if let Some(path) = &current {
+                changes.entry(path.clone()).or_default();
+            }
+        }
+    }
+
+    changes
+}
+
+/// Extract the new-file `(start, count)` from a `@@ -a,b +c,d @@` header
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    let new_range = line
+        .split_whitespace()
+        .find_map(|part| part.strip_prefix('+'))?;
+
+    match new_range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((new_range.parse().ok()?, 1)),
+    }
+}
+
+/// Get files staged for commit, relative to `repo_path`, limited to `pathspecs` if any
+pub fn get_staged_files(repo_path: &Path, pathspecs: &[String]) -> Result<Vec<PathBuf>> {
+    if !is_git_repository(repo_path)? {
+        return Err(anyhow!("not a git repository"));
+    }
+
+    // Deleted files have nothing left to check
+    list_paths(
+        repo_path,
+        &["diff", "--cached", "--diff-filter=ACMR"],
+        pathspecs,
+    )
+}
+
+/// Get files whose working tree content differs from the index
+pub fn get_unstaged_files(repo_path: &Path) -> Result<Vec<PathBuf>> {
+    list_paths(repo_path, &["diff"], &[])
+}
+
+/// Stage the working tree content of `path`
+pub fn stage_file(path: &Path, repo_path: &Path) -> Result<()> {
+    let output = Command::new("git")
+        .arg("add")
+        .arg("--")
+        .arg(path)
+        .current_dir(repo_path)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to stage {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+fn list_paths(repo_path: &Path, args: &[&str], pathspecs: &[String]) -> Result<Vec<PathBuf>> {
+    let output = Command::new("git")
+        .args(args)
+        .args(["--name-only", "--relative", "-z", "--"])
+        .args(pathspecs)
+        .current_dir(repo_path)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to list files: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// File reader that reads staged content from the git index instead of the working tree
+///
+/// Blobs come from a single `git cat-file --batch` process and are cached per
+/// path, as checking a file takes its metadata, a sniff and a full read.
+pub struct IndexFileReader {
+    repo_path: PathBuf,
+    batch: Mutex<Option<CatFileBatch>>,
+    blobs: Mutex<HashMap<PathBuf, Arc<Vec<u8>>>>,
+}
+
+/// A running `git cat-file --batch` process
+struct CatFileBatch {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl IndexFileReader {
+    /// Create a reader resolving relative paths against `repo_path`
+    pub fn new(repo_path: impl Into<PathBuf>) -> Self {
+        Self {
+            repo_path: repo_path.into(),
+            batch: Mutex::new(None),
+            blobs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Drop the cached blob of `path` once it is no longer needed
+    pub fn forget(&self, path: &Path) {
+        self.blobs.lock().unwrap().remove(path);
+    }
+
+    fn blob(&self, path: &Path) -> io::Result<Arc<Vec<u8>>> {
+        if let Some(blob) = self.blobs.lock().unwrap().get(path) {
+            return Ok(Arc::clone(blob));
+        }
+
+        let blob = Arc::new(self.cat_file(path)?);
+        self.blobs
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), Arc::clone(&blob));
+        Ok(blob)
+    }
+
+    fn cat_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let relative = path.strip_prefix(&self.repo_path).unwrap_or(path);
+        let mut batch = self.batch.lock().unwrap();
+        if batch.is_none() {
+            *batch = Some(CatFileBatch::spawn(&self.repo_path)?);
+        }
+        let process = batch.as_mut().expect("spawned above");
+
+        let result = process.request(&format!(":./{}", relative.display()));
+        if result
+            .as_ref()
+            .is_err_and(|e| e.kind() != io::ErrorKind::NotFound)
+        {
+            // The stream is out of step; start a new process next time
+            *batch = None;
+        }
+        result
+    }
+}
+
+impl CatFileBatch {
+    fn spawn(repo_path: &Path) -> io::Result<Self> {
+        let mut child = Command::new("git")
+            .args(["cat-file", "--batch"])
+            .current_dir(repo_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        Ok(Self {
+            child,
+            stdin,
+            stdout,
+        })
+    }
+
+    /// Read the object named `object`, answered as `<oid> <type> <size>`,
+    /// the content and a newline, or `<object> missing`
+    fn request(&mut self, object: &str) -> io::Result<Vec<u8>> {
+        writeln!(self.stdin, "{object}")?;
+        self.stdin.flush()?;
+
+        let mut header = String::new();
+        self.stdout.read_line(&mut header)?;
+        let size = match header.trim_end().rsplit_once(' ') {
+            Some((_, "missing" | "ambiguous")) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("not staged: {}", object.trim_start_matches(":./")),
+                ));
+            },
+            Some((_, size)) => size.parse::<usize>().ok(),
+            None => None,
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unexpected git cat-file output: {}", header.trim_end()),
+            )
+        })?;
+
+        let mut content = vec![0; size + 1];
+        self.stdout.read_exact(&mut content)?;
+        content.pop();
+        Ok(content)
+    }
+}
+
+impl Drop for CatFileBatch {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl FileReader for IndexFileReader {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        String::from_utf8(self.read(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        Ok(self.blob(path)?.as_ref().clone())
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn io::Read>> {
+        Ok(Box::new(io::Cursor::new(SharedBlob(self.blob(path)?))))
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        Ok(FileMetadata {
+            len: self.blob(path)?.len() as u64,
+            is_file: true,
+            is_dir: false,
+        })
+    }
+}
+
+/// A cached blob that can back an `io::Cursor` without being copied
+struct SharedBlob(Arc<Vec<u8>>);
+
+impl AsRef<[u8]> for SharedBlob {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// Check if the current directory is a git repository
 pub fn is_git_repository(path: &Path) -> Result<bool> {
     let output = Command::new("git")
@@ -356,4 +684,145 @@ mod tests {
         assert_eq!(changed_files.len(), 1);
         assert!(changed_files[0].file_name().unwrap() == "file2.txt");
     }
+
+    #[test]
+    fn test_parse_diff_hunks() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3 +3 @@ fn main() {
+-old
++new
+@@ -10,2 +10,0 @@
+-gone
+-gone
+@@ -20,0 +19,3 @@
++++ added line that looks like a header
++b
++c
+diff --git a/old.txt b/old.txt
+deleted file mode 100644
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+";
+        let changes = parse_diff_hunks(diff);
+
+        assert_eq!(changes.len(), 1);
+        let lines = &changes[&PathBuf::from("src/lib.rs")];
+        assert_eq!(lines.ranges, vec![(3, 3), (19, 21)]);
+        assert!(lines.contains(20));
+        assert!(!lines.contains(10));
+    }
+
+    #[test]
+    fn test_retain_issues_keeps_file_level_issue_only_for_changed_last_line() {
+        use crate::checker::{IssueType, Severity};
+
+        let lines = ChangedLines {
+            ranges: vec![(2, 3)],
+        };
+        let issues = vec![
+            Issue {
+                issue_type: IssueType::TrailingSpace,
+                line: Some(1),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "Trailing spaces found".to_string(),
+            },
+            Issue {
+                issue_type: IssueType::TrailingSpace,
+                line: Some(2),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "Trailing spaces found".to_string(),
+            },
+            Issue {
+                issue_type: IssueType::MissingNewline,
+                line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
+                message: "Missing newline at end of file".to_string(),
+            },
+        ];
+
+        let mut kept = issues.clone();
+        lines.retain_issues(&mut kept, 3);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[1].issue_type, IssueType::MissingNewline);
+
+        let mut kept = issues;
+        lines.retain_issues(&mut kept, 10);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_get_changed_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        init_test_repo(&temp_dir).unwrap();
+
+        let first = create_test_commit(&temp_dir, "file.txt", "a\nb\nc\n", "First commit");
+        create_test_commit(&temp_dir, "file.txt", "a\nB\nc\nd\n", "Second commit");
+
+        let changes = get_changed_lines(&first, None, temp_dir.path()).unwrap();
+        let lines = &changes[&temp_dir.path().join("file.txt")];
+        assert_eq!(lines.ranges, vec![(2, 2), (4, 4)]);
+    }
+
+    #[test]
+    fn test_get_changed_lines_ignores_diff_prefix_config() {
+        let temp_dir = TempDir::new().unwrap();
+        init_test_repo(&temp_dir).unwrap();
+        Command::new("git")
+            .args(["config", "diff.noprefix", "true"])
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+
+        let first = create_test_commit(&temp_dir, "file.txt", "a\nb\n", "First commit");
+        create_test_commit(&temp_dir, "file.txt", "a\nB\n", "Second commit");
+
+        let changes = get_changed_lines(&first, None, temp_dir.path()).unwrap();
+        let lines = &changes[&temp_dir.path().join("file.txt")];
+        assert_eq!(lines.ranges, vec![(2, 2)]);
+    }
+
+    #[test]
+    fn test_index_file_reader_reads_staged_content() {
+        let temp_dir = TempDir::new().unwrap();
+        init_test_repo(&temp_dir).unwrap();
+
+        std::fs::write(temp_dir.path().join("file.txt"), "staged\n").unwrap();
+        Command::new("git")
+            .args(["add", "file.txt"])
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+        std::fs::write(temp_dir.path().join("file.txt"), "working tree\n").unwrap();
+
+        let reader = IndexFileReader::new(temp_dir.path());
+        let path = PathBuf::from("file.txt");
+        assert_eq!(reader.read(&path).unwrap(), b"staged\n");
+        assert_eq!(reader.metadata(&path).unwrap().len, 7);
+        assert!(reader.read(&PathBuf::from("missing.txt")).is_err());
+
+        // The batch process survives misses, and forgotten blobs are re-read
+        reader.forget(&path);
+        assert_eq!(reader.read(&path).unwrap(), b"staged\n");
+
+        let staged = get_staged_files(temp_dir.path(), &[]).unwrap();
+        assert_eq!(staged, vec![PathBuf::from("file.txt")]);
+        let unstaged = get_unstaged_files(temp_dir.path()).unwrap();
+        assert_eq!(unstaged, vec![PathBuf::from("file.txt")]);
+    }
 }
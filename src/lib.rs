@@ -1,3 +1,4 @@
+pub mod baseline;
 pub mod checker;
 pub mod cli;
 pub mod config;
@@ -11,13 +12,13 @@ pub mod testing;
 
 // Re-export specific items to avoid conflicts
 pub use checker::{
-    CheckResult, FileChecker, Issue, IssueType, StdFileReader, check_file, check_newline_ending,
-    check_trailing_spaces,
+    CheckResult, Column, FileChecker, Issue, IssueType, Severity, StdFileReader, check_file,
+    check_newline_ending, check_trailing_spaces,
 };
 pub use cli::*;
 pub use config::*;
 pub use discovery::*;
 pub use reporter::{
-    Color, ColoredOutput, GitHubReporter, HumanReporter, JsonReporter, Output, Reporter,
-    ReporterWithOutput, StdOutput,
+    Color, ColoredOutput, GitHubReporter, HumanReporter, JUnitReporter, JsonReporter, Output,
+    Reporter, ReporterWithOutput, SarifReporter, StdOutput,
 };
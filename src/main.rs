@@ -1,11 +1,18 @@
 use indicatif::{ProgressBar, ProgressStyle};
-use lineguard::checker::check_file;
+use lineguard::baseline::{self, Baseline};
+use lineguard::checker::paths::check_paths;
+use lineguard::checker::{FileChecker, FileReader, check_file};
 use lineguard::cli::{OutputFormat, parse_args};
 use lineguard::config::load_config;
 use lineguard::discovery::discover_files;
 use lineguard::fixer::fix_file;
-use lineguard::reporter::{GitHubReporter, HumanReporter, JsonReporter, Reporter};
+use lineguard::git::{self, IndexFileReader};
+use lineguard::reporter::{
+    GitHubReporter, HumanReporter, JUnitReporter, JsonReporter, Reporter, SarifReporter,
+};
 use rayon::prelude::*;
+use std::collections::HashSet;
+use std::path::Path;
 use std::process;
 use std::sync::{Arc, Mutex};
 
@@ -21,13 +28,53 @@ fn main() {
         },
     };
 
-    // Apply CLI flags to override config
+    // Apply CLI flags to override config, including any per-path overrides
     if args.no_newline_check {
         config.checks.newline_ending = false;
+        config.explicit_checks.newline_ending = Some(false);
+        for override_ in &mut config.overrides {
+            override_.checks.newline_ending = None;
+        }
     }
     if args.no_trailing_space {
         config.checks.trailing_spaces = false;
+        config.checks.whitespace_only_lines = Some(false);
+        config.explicit_checks.trailing_spaces = Some(false);
+        config.explicit_checks.whitespace_only_lines = Some(false);
+        for override_ in &mut config.overrides {
+            override_.checks.trailing_spaces = None;
+            override_.checks.whitespace_only_lines = None;
+        }
+    }
+    if let Some(max_line_length) = args.max_line_length {
+        config.checks.max_line_length = Some(max_line_length);
+        config.explicit_checks.max_line_length = Some(Some(max_line_length));
+        for override_ in &mut config.overrides {
+            override_.checks.max_line_length = None;
+        }
+    }
+
+    if let Some(select) = &args.select {
+        config.select = Some(select.clone());
     }
+    if let Some(select) = &mut config.select {
+        select.extend(&args.extend_select);
+    }
+    config.ignore.extend(&args.ignore_rule);
+    if args.report_unused_suppressions {
+        config.report_unused_suppressions = true;
+    }
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("Error loading baseline: {e:#}");
+                process::exit(4);
+            },
+        },
+        None => None,
+    };
 
     // Discover files to check
     let discovery_result = match discover_files(&args, &config) {
@@ -39,11 +86,15 @@ fn main() {
     };
 
     let files = discovery_result.files;
+    let git_range = discovery_result.git_range;
+
+    // Check file names once, across every discovered path
+    let path_issues = check_paths(&files, &config);
 
     // Show git range info in verbose mode
     if args.verbose
-        && discovery_result.git_range.is_some()
-        && let Some(git_info) = &discovery_result.git_range
+        && git_range.is_some()
+        && let Some(git_info) = &git_range
     {
         println!(
             "Git range: {}..{}",
@@ -96,22 +147,93 @@ fn main() {
     let fix_mode = args.fix;
     let dry_run = args.dry_run;
 
+    // With --staged, check the index content rather than the working tree
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let index_reader = IndexFileReader::new(&cwd);
+    let index_checker = args
+        .staged
+        .then(|| FileChecker::new(&index_reader, config.clone()));
+    let uses_baseline = baseline.is_some() || args.write_baseline.is_some();
+
+    // Check a file, keeping only the issues on changed lines and not in the baseline
+    let check = |file_path: &Path| {
+        let mut result = match &index_checker {
+            Some(checker) => checker.check_file(file_path),
+            None => check_file(file_path, &config_arc),
+        };
+        if let Some(git_info) = &git_range {
+            git_info.retain_changed_issues(&mut result);
+        }
+        if let Some(issues) = path_issues.get(file_path) {
+            result.issues.splice(0..0, issues.iter().cloned());
+        }
+
+        // Baseline entries hash line content, so re-read files that have issues
+        let content = if uses_baseline && !result.issues.is_empty() {
+            let content = if args.staged {
+                index_reader.read(file_path)
+            } else {
+                std::fs::read(file_path)
+            };
+            content.unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let baseline_entries = if args.write_baseline.is_some() {
+            baseline::entries_for(&result, &content)
+        } else {
+            Vec::new()
+        };
+        let baseline_match = baseline
+            .as_ref()
+            .map(|baseline| baseline.apply(&mut result, &content))
+            .unwrap_or_default();
+        if args.staged {
+            index_reader.forget(file_path);
+        }
+
+        (result, baseline_match, baseline_entries)
+    };
+
+    // Fixing a partially staged file would mix unstaged changes into the commit
+    let unstaged: HashSet<_> = if args.staged && fix_mode {
+        match git::get_unstaged_files(&cwd) {
+            Ok(files) => files.into_iter().collect(),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                process::exit(3);
+            },
+        }
+    } else {
+        HashSet::new()
+    };
+
     if fix_mode {
         // Fix mode: check and fix files
         let fix_results: Vec<_> = files
             .par_iter()
             .map(|file_path| {
-                let check_result = check_file(file_path, &config_arc);
-                let fix_result = if !check_result.issues.is_empty() {
-                    fix_file(file_path, &check_result.issues, &config_arc, dry_run)
-                } else {
+                let (check_result, _, _) = check(file_path);
+                let fix_result = if check_result.issues.is_empty() {
                     Ok(lineguard::fixer::FixResult {
                         file_path: file_path.clone(),
                         fixed: false,
                         issues_fixed: vec![],
                     })
+                } else if unstaged.contains(file_path) {
+                    Err(anyhow::anyhow!(
+                        "partially staged, stage or stash its unstaged changes before fixing"
+                    ))
+                } else {
+                    fix_file(file_path, &check_result.issues, &config_arc, dry_run).and_then(
+                        |fix| {
+                            if args.staged && fix.fixed && !dry_run {
+                                git::stage_file(file_path, &cwd)?;
+                            }
+                            Ok(fix)
+                        },
+                    )
                 };
-
                 if let Some(pb) = &pb_mutex
                     && let Ok(pb) = pb.lock()
                 {
@@ -136,16 +258,16 @@ fn main() {
         process::exit(if has_errors { 1 } else { 0 });
     } else {
         // Normal check mode
-        let all_results: Vec<_> = files
+        let checked: Vec<_> = files
             .par_iter()
             .map(|file_path| {
-                let result = check_file(file_path, &config_arc);
+                let checked = check(file_path);
                 if let Some(pb) = &pb_mutex
                     && let Ok(pb) = pb.lock()
                 {
                     pb.inc(1);
                 }
-                result
+                checked
             })
             .collect();
 
@@ -153,10 +275,34 @@ fn main() {
             pb.finish_and_clear();
         }
 
+        let mut all_results = Vec::with_capacity(checked.len());
+        let mut baseline_fixed = 0;
+        let mut baseline_entries = Vec::new();
+        for (result, baseline_match, entries) in checked {
+            all_results.push(result);
+            baseline_fixed += baseline_match.fixed;
+            baseline_entries.extend(entries);
+        }
+
+        // Record the current issues instead of reporting them
+        if let Some(path) = &args.write_baseline {
+            let count = baseline_entries.len();
+            if let Err(e) = Baseline::write(path, baseline_entries) {
+                eprintln!("Error: {e:#}");
+                process::exit(3);
+            }
+            if !args.quiet {
+                println!("Wrote {count} issues to baseline {}", path.display());
+            }
+            process::exit(0);
+        }
+
         // Create appropriate reporter
         let reporter: Box<dyn Reporter> = match args.format {
             OutputFormat::Json => Box::new(JsonReporter::new()),
             OutputFormat::GitHub => Box::new(GitHubReporter::new()),
+            OutputFormat::Sarif => Box::new(SarifReporter::new()),
+            OutputFormat::JUnit => Box::new(JUnitReporter::new()),
             OutputFormat::Human => Box::new(HumanReporter {
                 use_color: !args.no_color,
             }),
@@ -182,8 +328,26 @@ fn main() {
             reporter.report(&all_results);
         }
 
-        // Exit with 1 only if there are actual lint issues, not permission errors
-        process::exit(if has_issues { 1 } else { 0 });
+        if baseline_fixed > 0 && !args.quiet {
+            let message = format!(
+                "{baseline_fixed} baselined issues have been fixed; \
+                 run with --write-baseline to update the baseline"
+            );
+            // Keep machine-readable output on stdout parseable
+            if args.format == OutputFormat::Human {
+                println!("{message}");
+            } else {
+                eprintln!("{message}");
+            }
+        }
+
+        // Exit with 1 only if there are lint issues at or above the --fail-on
+        // severity, not for permission errors
+        let has_failures = all_results
+            .iter()
+            .flat_map(|r| &r.issues)
+            .any(|issue| issue.severity >= args.fail_on);
+        process::exit(if has_failures { 1 } else { 0 });
     }
 }
 
@@ -271,7 +435,7 @@ fn report_fix_results_to_writers<W1: std::io::Write, W2: std::io::Write>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use lineguard::checker::{CheckResult, Issue, IssueType};
+    use lineguard::checker::{CheckResult, Issue, IssueType, Severity};
     use lineguard::cli::{CliArgs, OutputFormat};
     use lineguard::fixer::FixResult;
     use std::path::PathBuf;
@@ -291,9 +455,20 @@ mod tests {
             verbose: false,
             no_newline_check: false,
             no_trailing_space: false,
+            max_line_length: None,
             from: None,
             to: None,
             no_hidden: false,
+            select: None,
+            extend_select: vec![],
+            ignore_rule: vec![],
+            fail_on: Severity::Error,
+            write_baseline: None,
+            baseline: None,
+            report_unused_suppressions: false,
+            staged: false,
+            changed_lines_only: false,
+            no_ignore_vcs: false,
         }
     }
 
@@ -304,6 +479,10 @@ mod tests {
                 vec![Issue {
                     line: Some(1),
                     issue_type: IssueType::MissingNewline,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Missing newline at end of file".to_string(),
                 }]
             } else {
@@ -321,6 +500,10 @@ mod tests {
                 vec![Issue {
                     line: Some(1),
                     issue_type: IssueType::MissingNewline,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Missing newline at end of file".to_string(),
                 }]
             } else {
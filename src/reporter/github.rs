@@ -2,14 +2,14 @@
 //!
 //! This module provides a reporter that outputs results in GitHub Actions annotation format.
 
-use crate::CheckResult;
 use crate::reporter::{Output, Reporter, ReporterWithOutput};
+use crate::{CheckResult, Severity};
 use std::io;
 
 /// GitHub Actions format reporter
 ///
-/// This reporter outputs issues as GitHub Actions error annotations
-/// that will be displayed in pull request reviews and workflow runs.
+/// This reporter outputs issues as GitHub Actions annotations, at the level
+/// matching each issue's severity, that will be displayed in pull request reviews and workflow runs.
 pub struct GitHubReporter;
 
 impl GitHubReporter {
@@ -49,18 +49,26 @@ impl ReporterWithOutput for GitHubReporter {
 
             // Report issues
             for issue in &result.issues {
-                let file = result.file_path.display();
-                match issue.line {
-                    Some(line) => {
-                        output.write_line(&format!(
-                            "::error file={},line={}::{}",
-                            file, line, issue.message
-                        ))?;
-                    },
-                    None => {
-                        output.write_line(&format!("::error file={}::{}", file, issue.message))?;
-                    },
+                let mut location = format!("file={}", result.file_path.display());
+                if let Some(line) = issue.line {
+                    location.push_str(&format!(",line={line}"));
+                    if let Some(end_line) = issue.end_line {
+                        location.push_str(&format!(",endLine={end_line}"));
+                    }
+                    if let Some(column) = issue.column {
+                        location.push_str(&format!(",col={}", column.character));
+                    }
+                    if let Some(end_column) = issue.end_column {
+                        location.push_str(&format!(",endColumn={}", end_column.character));
+                    }
                 }
+                output.write_line(&format!(
+                    "::{} {}::{} [{}]",
+                    annotation_command(issue.severity),
+                    location,
+                    issue.message,
+                    issue.issue_type.code()
+                ))?;
             }
         }
 
@@ -69,6 +77,15 @@ impl ReporterWithOutput for GitHubReporter {
     }
 }
 
+/// Workflow command for an annotation of `severity`
+fn annotation_command(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,11 +101,19 @@ mod tests {
                     Issue {
                         issue_type: IssueType::TrailingSpace,
                         line: Some(42),
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
                         message: "Trailing spaces found".to_string(),
                     },
                     Issue {
                         issue_type: IssueType::MissingNewline,
                         line: None,
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
                         message: "Missing newline at end of file".to_string(),
                     },
                 ],
@@ -160,16 +185,28 @@ mod tests {
                 Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(10),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Trailing spaces on line 10".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(20),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Trailing spaces on line 20".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::MultipleNewlines,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Multiple newlines at end of file".to_string(),
                 },
             ],
@@ -217,6 +254,10 @@ mod tests {
             issues: vec![Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(1),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Issue in file with spaces".to_string(),
             }],
             error: None,
@@ -256,6 +297,10 @@ mod tests {
             issues: vec![Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(5),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Trailing space detected".to_string(),
             }],
             error: Some("Partial read error".to_string()),
@@ -271,4 +316,68 @@ mod tests {
         assert!(lines[0].contains("::error file=problematic.txt::Partial read error"));
         assert!(lines[1].contains("::error file=problematic.txt,line=5::Trailing space detected"));
     }
+
+    #[test]
+    fn test_github_reporter_columns() {
+        let reporter = GitHubReporter::new();
+        let mut output = MockOutput::new();
+        let results = vec![CheckResult {
+            file_path: PathBuf::from("test.txt"),
+            issues: vec![Issue {
+                issue_type: IssueType::TrailingSpace,
+                line: Some(3),
+                end_line: None,
+                column: Some(crate::Column {
+                    character: 5,
+                    byte: 6,
+                }),
+                end_column: Some(crate::Column {
+                    character: 8,
+                    byte: 9,
+                }),
+                severity: Severity::Error,
+                message: "Trailing spaces found".to_string(),
+            }],
+            error: None,
+        }];
+
+        reporter.report_to(&results, &mut output).unwrap();
+
+        assert_eq!(
+            output.get_output().trim_end(),
+            "::error file=test.txt,line=3,col=5,endColumn=8::Trailing spaces found [LG003]"
+        );
+    }
+
+    #[test]
+    fn test_github_reporter_severity_commands() {
+        let reporter = GitHubReporter::new();
+        let mut output = MockOutput::new();
+        let issue = |severity, line| Issue {
+            issue_type: IssueType::TrailingSpace,
+            line: Some(line),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity,
+            message: "Trailing spaces found".to_string(),
+        };
+        let results = vec![CheckResult {
+            file_path: PathBuf::from("test.txt"),
+            issues: vec![
+                issue(Severity::Error, 1),
+                issue(Severity::Warning, 2),
+                issue(Severity::Info, 3),
+            ],
+            error: None,
+        }];
+
+        reporter.report_to(&results, &mut output).unwrap();
+
+        let output_str = output.get_output();
+        let lines: Vec<&str> = output_str.lines().collect();
+        assert!(lines[0].starts_with("::error file=test.txt,line=1::"));
+        assert!(lines[1].starts_with("::warning file=test.txt,line=2::"));
+        assert!(lines[2].starts_with("::notice file=test.txt,line=3::"));
+    }
 }
@@ -4,7 +4,7 @@
 //! with optional color support.
 
 use crate::reporter::{Color, ColoredOutput, Output, Reporter, ReporterWithOutput};
-use crate::{CheckResult, Issue};
+use crate::{CheckResult, Issue, Severity};
 use std::io;
 
 /// Human-readable reporter with color support
@@ -134,15 +134,24 @@ impl HumanReporter {
         Ok(())
     }
 
-    /// Write individual issue
+    /// Write individual issue, labelled with its severity
     fn write_issue(&self, issue: &Issue, output: &mut dyn ColoredOutput) -> io::Result<()> {
-        match issue.line {
-            Some(line) => {
-                output.write_line(&format!("  - Line {line}: {}", issue.message))?;
-            },
-            None => {
-                output.write_line(&format!("  - {}", issue.message))?;
+        let code = issue.issue_type.code();
+        let label = format!("{:<7}", issue.severity.name());
+        let text = match (issue.line, issue.end_line) {
+            (Some(line), Some(end_line)) => {
+                format!("Lines {line}-{end_line}: {} [{code}]", issue.message)
             },
+            (Some(line), None) => format!("Line {line}: {} [{code}]", issue.message),
+            (None, _) => format!("{} [{code}]", issue.message),
+        };
+
+        if self.use_color {
+            output.write("  ")?;
+            output.write_colored(&label, severity_color(issue.severity))?;
+            output.write_line(&format!(" {text}"))?;
+        } else {
+            output.write_line(&format!("  {label} {text}"))?;
         }
         Ok(())
     }
@@ -178,11 +187,20 @@ impl HumanReporter {
     }
 }
 
+/// Color of a severity label
+fn severity_color(severity: Severity) -> Color {
+    match severity {
+        Severity::Error => Color::Red,
+        Severity::Warning => Color::Yellow,
+        Severity::Info => Color::Cyan,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::IssueType;
     use crate::testing::mocks::MockOutput;
-    use crate::{Issue, IssueType};
     use std::path::PathBuf;
 
     fn create_test_results() -> Vec<CheckResult> {
@@ -193,11 +211,19 @@ mod tests {
                     Issue {
                         issue_type: IssueType::TrailingSpace,
                         line: Some(5),
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
                         message: "Trailing spaces found".to_string(),
                     },
                     Issue {
                         issue_type: IssueType::MissingNewline,
                         line: None,
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
                         message: "Missing newline at end of file".to_string(),
                     },
                 ],
@@ -213,6 +239,10 @@ mod tests {
                 issues: vec![Issue {
                     issue_type: IssueType::MultipleNewlines,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Multiple newlines at end of file".to_string(),
                 }],
                 error: None,
@@ -316,6 +346,10 @@ mod tests {
             issues: vec![Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(10),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Trailing spaces found".to_string(),
             }],
             error: None,
@@ -411,4 +445,43 @@ mod tests {
         assert!(output_str.contains("✓ All files passed lint checks!"));
         assert!(output_str.contains("Files checked: 3"));
     }
+
+    #[test]
+    fn test_human_reporter_severity_labels() {
+        let issue = |severity, line| Issue {
+            issue_type: IssueType::TrailingSpace,
+            line: Some(line),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity,
+            message: "Trailing spaces found".to_string(),
+        };
+        let results = vec![CheckResult {
+            file_path: PathBuf::from("test.txt"),
+            issues: vec![
+                issue(Severity::Error, 1),
+                issue(Severity::Warning, 2),
+                issue(Severity::Info, 3),
+            ],
+            error: None,
+        }];
+
+        let mut output = MockOutput::new();
+        HumanReporter::new()
+            .report_to(&results, &mut output)
+            .unwrap();
+        let buffer = output.get_output();
+        assert!(buffer.contains("  error   Line 1: Trailing spaces found [LG003]"));
+        assert!(buffer.contains("  warning Line 2: Trailing spaces found [LG003]"));
+        assert!(buffer.contains("  info    Line 3: Trailing spaces found [LG003]"));
+
+        let mut output = MockOutput::new();
+        HumanReporter::with_color()
+            .report_to_colored(&results, &mut output)
+            .unwrap();
+        assert!(output.contains_colored("error  ", Color::Red));
+        assert!(output.contains_colored("warning", Color::Yellow));
+        assert!(output.contains_colored("info   ", Color::Cyan));
+    }
 }
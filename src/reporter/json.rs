@@ -2,8 +2,8 @@
 //!
 //! This module provides a reporter that outputs results in JSON format.
 
+use crate::CheckResult;
 use crate::reporter::{Output, Reporter, ReporterWithOutput};
-use crate::{CheckResult, IssueType};
 use serde_json::json;
 use std::io;
 
@@ -65,12 +65,15 @@ impl ReporterWithOutput for JsonReporter {
                     .iter()
                     .map(|issue| {
                         json!({
-                            "type": match issue.issue_type {
-                                IssueType::MissingNewline => "missing_newline",
-                                IssueType::MultipleNewlines => "multiple_newlines",
-                                IssueType::TrailingSpace => "trailing_space",
-                            },
+                            "type": issue.issue_type.name(),
+                            "code": issue.issue_type.code(),
+                            "level": issue.severity.name(),
                             "line": issue.line,
+                            "end_line": issue.end_line,
+                            "column": issue.column.map(|c| c.character),
+                            "end_column": issue.end_column.map(|c| c.character),
+                            "byte_column": issue.column.map(|c| c.byte),
+                            "byte_end_column": issue.end_column.map(|c| c.byte),
                             "message": issue.message,
                         })
                     })
@@ -111,7 +114,7 @@ impl ReporterWithOutput for JsonReporter {
 mod tests {
     use super::*;
     use crate::testing::mocks::MockOutput;
-    use crate::{Issue, IssueType};
+    use crate::{Issue, IssueType, Severity};
     use std::path::PathBuf;
 
     fn create_test_results() -> Vec<CheckResult> {
@@ -122,11 +125,19 @@ mod tests {
                     Issue {
                         issue_type: IssueType::TrailingSpace,
                         line: Some(5),
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
                         message: "Trailing spaces found".to_string(),
                     },
                     Issue {
                         issue_type: IssueType::MissingNewline,
                         line: None,
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
                         message: "Missing newline at end of file".to_string(),
                     },
                 ],
@@ -192,6 +203,40 @@ mod tests {
         assert!(json.get("errors").is_none());
     }
 
+    #[test]
+    fn test_json_reporter_columns() {
+        let reporter = JsonReporter::new();
+        let mut output = MockOutput::new();
+        let results = vec![CheckResult {
+            file_path: PathBuf::from("test.txt"),
+            issues: vec![Issue {
+                issue_type: IssueType::TrailingSpace,
+                line: Some(1),
+                end_line: None,
+                column: Some(crate::Column {
+                    character: 6,
+                    byte: 7,
+                }),
+                end_column: Some(crate::Column {
+                    character: 7,
+                    byte: 8,
+                }),
+                severity: Severity::Error,
+                message: "Trailing spaces found".to_string(),
+            }],
+            error: None,
+        }];
+
+        reporter.report_to(&results, &mut output).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&output.get_output()).unwrap();
+        let issue = &json["issues"][0]["issues"][0];
+        assert_eq!(issue["column"], 6);
+        assert_eq!(issue["end_column"], 7);
+        assert_eq!(issue["byte_column"], 7);
+        assert_eq!(issue["byte_end_column"], 8);
+    }
+
     #[test]
     fn test_json_reporter_compact() {
         let reporter = JsonReporter::compact();
@@ -201,6 +246,10 @@ mod tests {
             issues: vec![Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(1),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Trailing spaces".to_string(),
             }],
             error: None,
@@ -223,16 +272,28 @@ mod tests {
                 Issue {
                     issue_type: IssueType::MissingNewline,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Missing newline".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::MultipleNewlines,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Warning,
                     message: "Multiple newlines".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(10),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Info,
                     message: "Trailing space".to_string(),
                 },
             ],
@@ -249,6 +310,9 @@ mod tests {
         assert_eq!(file_issues[1]["type"], "multiple_newlines");
         assert_eq!(file_issues[2]["type"], "trailing_space");
         assert_eq!(file_issues[2]["line"], 10);
+        assert_eq!(file_issues[0]["level"], "error");
+        assert_eq!(file_issues[1]["level"], "warning");
+        assert_eq!(file_issues[2]["level"], "info");
     }
 
     #[test]
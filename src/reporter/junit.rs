@@ -0,0 +1,202 @@
+//! JUnit XML reporter implementation
+//!
+//! This module provides a reporter that outputs results as a JUnit XML test
+//! suite, with one test case per checked file.
+
+use crate::CheckResult;
+use crate::reporter::{Output, Reporter, ReporterWithOutput};
+use std::io;
+
+/// JUnit XML format reporter
+pub struct JUnitReporter;
+
+impl JUnitReporter {
+    /// Create a new JUnit reporter
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JUnitReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for JUnitReporter {
+    fn report(&self, results: &[CheckResult]) {
+        // Use StdOutput as the default output
+        let mut output = crate::reporter::StdOutput::new();
+
+        // Ignore any errors from output operations in the legacy interface
+        let _ = self.report_to(results, &mut output);
+    }
+}
+
+impl ReporterWithOutput for JUnitReporter {
+    fn report_to(&self, results: &[CheckResult], output: &mut dyn Output) -> io::Result<()> {
+        let failures = results.iter().filter(|r| !r.issues.is_empty()).count();
+        let errors = results.iter().filter(|r| r.error.is_some()).count();
+
+        output.write_line(r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        output.write_line(&format!(
+            r#"<testsuite name="lineguard" tests="{}" failures="{}" errors="{}">"#,
+            results.len(),
+            failures,
+            errors
+        ))?;
+
+        for result in results {
+            let name = escape(&result.file_path.display().to_string());
+            if result.issues.is_empty() && result.error.is_none() {
+                output.write_line(&format!(
+                    r#"  <testcase name="{name}" classname="lineguard"/>"#
+                ))?;
+                continue;
+            }
+
+            output.write_line(&format!(
+                r#"  <testcase name="{name}" classname="lineguard">"#
+            ))?;
+
+            if let Some(error) = &result.error {
+                let error = escape(error);
+                output.write_line(&format!(
+                    r#"    <error type="read_error" message="{error}">{error}</error>"#
+                ))?;
+            }
+
+            for issue in &result.issues {
+                let message = escape(&format!("{} [{}]", issue.message, issue.issue_type.code()));
+                let location = match issue.line {
+                    Some(line) => format!("{}:{}", name, line),
+                    None => name.clone(),
+                };
+                output.write_line(&format!(
+                    r#"    <failure type="{}" message="{}">{}: {}</failure>"#,
+                    issue.issue_type.name(),
+                    message,
+                    location,
+                    message
+                ))?;
+            }
+
+            output.write_line("  </testcase>")?;
+        }
+
+        output.write_line("</testsuite>")?;
+        output.flush()?;
+        Ok(())
+    }
+}
+
+/// Escape text for use in XML attributes and content, replacing characters XML cannot represent
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => escaped.push(char::REPLACEMENT_CHARACTER),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mocks::MockOutput;
+    use crate::{Issue, IssueType, Severity};
+    use std::path::PathBuf;
+
+    fn create_test_results() -> Vec<CheckResult> {
+        vec![
+            CheckResult {
+                file_path: PathBuf::from("src/main.rs"),
+                issues: vec![
+                    Issue {
+                        issue_type: IssueType::TrailingSpace,
+                        line: Some(42),
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
+                        message: "Trailing spaces found".to_string(),
+                    },
+                    Issue {
+                        issue_type: IssueType::MissingNewline,
+                        line: None,
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
+                        message: "Missing newline at end of file".to_string(),
+                    },
+                ],
+                error: None,
+            },
+            CheckResult {
+                file_path: PathBuf::from("src/lib.rs"),
+                issues: vec![],
+                error: None,
+            },
+            CheckResult {
+                file_path: PathBuf::from("src/error.rs"),
+                issues: vec![],
+                error: Some("Permission denied".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_junit_reporter_basic() {
+        let reporter = JUnitReporter::new();
+        let mut output = MockOutput::new();
+
+        reporter
+            .report_to(&create_test_results(), &mut output)
+            .unwrap();
+
+        let output_str = output.get_output();
+        assert!(output_str.starts_with("<?xml"));
+        assert!(output_str.contains(r#"tests="3" failures="1" errors="1""#));
+        assert!(
+            output_str.contains(
+                r#"<failure type="trailing_space" message="Trailing spaces found [LG003]">src/main.rs:42: Trailing spaces found [LG003]</failure>"#
+            )
+        );
+        assert!(output_str.contains(
+            r#"<failure type="missing_newline" message="Missing newline at end of file [LG001]">src/main.rs: "#
+        ));
+        assert!(output_str.contains(r#"<testcase name="src/lib.rs" classname="lineguard"/>"#));
+        assert!(output_str.contains(r#"<error type="read_error" message="Permission denied">"#));
+        assert!(output_str.trim_end().ends_with("</testsuite>"));
+    }
+
+    #[test]
+    fn test_junit_reporter_empty_results() {
+        let reporter = JUnitReporter::new();
+        let mut output = MockOutput::new();
+
+        reporter.report_to(&[], &mut output).unwrap();
+
+        let output_str = output.get_output();
+        assert!(output_str.contains(r#"tests="0" failures="0" errors="0""#));
+        assert!(!output_str.contains("<testcase"));
+    }
+
+    #[test]
+    fn test_junit_escape() {
+        assert_eq!(
+            escape(r#"<a href="x">&'"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&apos;"
+        );
+        assert_eq!(escape("bell\u{7}"), "bell\u{FFFD}");
+    }
+}
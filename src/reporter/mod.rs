@@ -1,6 +1,8 @@
 pub mod github;
 pub mod human;
 pub mod json;
+pub mod junit;
+pub mod sarif;
 pub mod traits;
 
 use crate::CheckResult;
@@ -12,6 +14,8 @@ pub use traits::{Color, ColoredOutput, Output, ReporterWithOutput, StdOutput};
 pub use github::GitHubReporter;
 pub use human::HumanReporter;
 pub use json::JsonReporter;
+pub use junit::JUnitReporter;
+pub use sarif::SarifReporter;
 
 pub trait Reporter {
     fn report(&self, results: &[CheckResult]);
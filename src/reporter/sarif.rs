@@ -0,0 +1,363 @@
+//! SARIF reporter implementation
+//!
+//! This module provides a reporter that outputs results in SARIF 2.1.0 format
+//! for code-scanning dashboards.
+
+use crate::reporter::{Output, Reporter, ReporterWithOutput};
+use crate::{CheckResult, IssueType, Severity};
+use serde_json::{Value, json};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+/// `uriBaseId` that relative artifact locations are resolved against
+pub const SRCROOT: &str = "SRCROOT";
+
+/// SARIF 2.1.0 format reporter
+pub struct SarifReporter {
+    /// Directory relative artifact URIs are resolved against, if known
+    pub base_dir: Option<PathBuf>,
+}
+
+impl SarifReporter {
+    /// Create a new SARIF reporter rooted at the current directory
+    pub fn new() -> Self {
+        Self {
+            base_dir: std::env::current_dir().ok(),
+        }
+    }
+
+    fn rules() -> Vec<Value> {
+        IssueType::ALL
+            .iter()
+            .map(|issue_type| {
+                let level = sarif_level(issue_type.default_severity());
+                json!({
+                    "id": issue_type.code(),
+                    "name": issue_type.name(),
+                    "shortDescription": { "text": issue_type.description() },
+                    "help": { "text": issue_type.help() },
+                    "defaultConfiguration": { "level": level },
+                })
+            })
+            .collect()
+    }
+
+    fn artifact_location(&self, path: &Path) -> Value {
+        let relative = match &self.base_dir {
+            Some(base) if path.is_absolute() => path.strip_prefix(base).ok(),
+            _ if path.is_relative() => Some(path),
+            _ => None,
+        };
+
+        match relative {
+            Some(relative) => json!({
+                "uri": path_to_uri(relative),
+                "uriBaseId": SRCROOT,
+            }),
+            None => json!({ "uri": format!("file://{}", path_to_uri(path)) }),
+        }
+    }
+}
+
+impl Default for SarifReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for SarifReporter {
+    fn report(&self, results: &[CheckResult]) {
+        // Use StdOutput as the default output
+        let mut output = crate::reporter::StdOutput::new();
+
+        // Ignore any errors from output operations in the legacy interface
+        let _ = self.report_to(results, &mut output);
+    }
+}
+
+impl ReporterWithOutput for SarifReporter {
+    fn report_to(&self, results: &[CheckResult], output: &mut dyn Output) -> io::Result<()> {
+        let mut sarif_results = Vec::new();
+        let mut notifications = Vec::new();
+
+        for result in results {
+            let artifact_location = self.artifact_location(&result.file_path);
+
+            if let Some(error) = &result.error {
+                notifications.push(json!({
+                    "level": "error",
+                    "message": { "text": error },
+                    "locations": [{
+                        "physicalLocation": { "artifactLocation": artifact_location },
+                    }],
+                }));
+            }
+
+            for issue in &result.issues {
+                let mut physical_location = json!({ "artifactLocation": artifact_location });
+                if let Some(line) = issue.line {
+                    let mut region = json!({ "startLine": line });
+                    if let Some(end_line) = issue.end_line {
+                        region["endLine"] = json!(end_line);
+                    }
+                    if let Some(column) = issue.column {
+                        region["startColumn"] = json!(column.character);
+                    }
+                    if let Some(end_column) = issue.end_column {
+                        region["endColumn"] = json!(end_column.character);
+                    }
+                    physical_location["region"] = region;
+                }
+
+                sarif_results.push(json!({
+                    "ruleId": issue.issue_type.code(),
+                    "ruleIndex": IssueType::ALL
+                        .iter()
+                        .position(|t| *t == issue.issue_type),
+                    "level": sarif_level(issue.severity),
+                    "message": { "text": issue.message },
+                    "locations": [{ "physicalLocation": physical_location }],
+                }));
+            }
+        }
+
+        let mut run = json!({
+            "tool": {
+                "driver": {
+                    "name": "lineguard",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/hydai/lineguard",
+                    "rules": Self::rules(),
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "invocations": [{
+                "executionSuccessful": notifications.is_empty(),
+                "toolExecutionNotifications": notifications,
+            }],
+            "results": sarif_results,
+        });
+
+        if let Some(base) = &self.base_dir {
+            run["originalUriBaseIds"] = json!({
+                SRCROOT: { "uri": format!("file://{}/", path_to_uri(base).trim_end_matches('/')) },
+            });
+        }
+
+        let sarif = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [run],
+        });
+
+        output.write_line(&serde_json::to_string_pretty(&sarif)?)?;
+        output.flush()?;
+        Ok(())
+    }
+}
+
+/// SARIF `level` for a severity
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "note",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+/// Convert a path to a percent-encoded URI path with `/` separators
+fn path_to_uri(path: &Path) -> String {
+    let mut segments = Vec::new();
+    let mut absolute = false;
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::RootDir => absolute = true,
+            Component::Prefix(prefix) => {
+                segments.push(prefix.as_os_str().to_string_lossy().into_owned())
+            },
+            other => segments.push(encode_segment(&other.as_os_str().to_string_lossy())),
+        }
+    }
+
+    let joined = segments.join("/");
+    if absolute && !joined.starts_with('/') {
+        format!("/{joined}")
+    } else {
+        joined
+    }
+}
+
+fn encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Issue;
+    use crate::testing::mocks::MockOutput;
+
+    fn create_test_results() -> Vec<CheckResult> {
+        vec![
+            CheckResult {
+                file_path: PathBuf::from("./src/main.rs"),
+                issues: vec![
+                    Issue {
+                        issue_type: IssueType::TrailingSpace,
+                        line: Some(42),
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Error,
+                        message: "Trailing spaces found".to_string(),
+                    },
+                    Issue {
+                        issue_type: IssueType::MissingNewline,
+                        line: None,
+                        end_line: None,
+                        column: None,
+                        end_column: None,
+                        severity: Severity::Info,
+                        message: "Missing newline at end of file".to_string(),
+                    },
+                ],
+                error: None,
+            },
+            CheckResult {
+                file_path: PathBuf::from("src/lib.rs"),
+                issues: vec![],
+                error: None,
+            },
+            CheckResult {
+                file_path: PathBuf::from("docs/my notes.md"),
+                issues: vec![],
+                error: Some("Permission denied".to_string()),
+            },
+        ]
+    }
+
+    fn report(reporter: &SarifReporter, results: &[CheckResult]) -> Value {
+        let mut output = MockOutput::new();
+        reporter.report_to(results, &mut output).unwrap();
+        serde_json::from_str(&output.get_output()).unwrap()
+    }
+
+    #[test]
+    fn test_sarif_reporter_structure() {
+        let reporter = SarifReporter {
+            base_dir: Some(PathBuf::from("/repo")),
+        };
+        let sarif = report(&reporter, &create_test_results());
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "lineguard");
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().unwrap().len(),
+            IssueType::ALL.len()
+        );
+        assert_eq!(run["originalUriBaseIds"]["SRCROOT"]["uri"], "file:///repo/");
+    }
+
+    #[test]
+    fn test_sarif_reporter_results() {
+        let reporter = SarifReporter {
+            base_dir: Some(PathBuf::from("/repo")),
+        };
+        let sarif = report(&reporter, &create_test_results());
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "LG003");
+        assert_eq!(results[0]["ruleIndex"], 2);
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/main.rs");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "SRCROOT");
+        assert_eq!(location["region"]["startLine"], 42);
+
+        assert_eq!(results[1]["ruleId"], "LG001");
+        assert_eq!(results[1]["level"], "note");
+        assert!(results[1]["locations"][0]["physicalLocation"]["region"].is_null());
+    }
+
+    #[test]
+    fn test_sarif_reporter_errors_as_notifications() {
+        let reporter = SarifReporter {
+            base_dir: Some(PathBuf::from("/repo")),
+        };
+        let sarif = report(&reporter, &create_test_results());
+        let invocation = &sarif["runs"][0]["invocations"][0];
+
+        assert_eq!(invocation["executionSuccessful"], false);
+        let notification = &invocation["toolExecutionNotifications"][0];
+        assert_eq!(notification["message"]["text"], "Permission denied");
+        assert_eq!(
+            notification["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "docs/my%20notes.md"
+        );
+    }
+
+    #[test]
+    fn test_sarif_reporter_absolute_paths() {
+        let reporter = SarifReporter {
+            base_dir: Some(PathBuf::from("/repo")),
+        };
+        let results = vec![
+            CheckResult {
+                file_path: PathBuf::from("/repo/a.txt"),
+                issues: vec![Issue {
+                    issue_type: IssueType::TrailingSpace,
+                    line: Some(1),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
+                    message: "Trailing spaces found".to_string(),
+                }],
+                error: None,
+            },
+            CheckResult {
+                file_path: PathBuf::from("/elsewhere/b.txt"),
+                issues: vec![Issue {
+                    issue_type: IssueType::TrailingSpace,
+                    line: Some(1),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
+                    message: "Trailing spaces found".to_string(),
+                }],
+                error: None,
+            },
+        ];
+        let sarif = report(&reporter, &results);
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+
+        let first = &results[0]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(first["uri"], "a.txt");
+        let second = &results[1]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(second["uri"], "file:///elsewhere/b.txt");
+        assert!(second["uriBaseId"].is_null());
+    }
+
+    #[test]
+    fn test_sarif_reporter_no_issues() {
+        let reporter = SarifReporter { base_dir: None };
+        let sarif = report(&reporter, &[]);
+        let run = &sarif["runs"][0];
+
+        assert!(run["results"].as_array().unwrap().is_empty());
+        assert_eq!(run["invocations"][0]["executionSuccessful"], true);
+        assert!(run["originalUriBaseIds"].is_null());
+    }
+}
@@ -32,7 +32,7 @@ pub fn dir_metadata() -> FileMetadata {
 
 /// Mock filesystem implementation
 pub struct MockFileSystem {
-    files: HashMap<PathBuf, String>,
+    files: HashMap<PathBuf, Vec<u8>>,
     metadata: HashMap<PathBuf, FileMetadata>,
     errors: HashMap<PathBuf, io::Error>,
 }
@@ -49,6 +49,11 @@ impl MockFileSystem {
 
     /// Add a file to the mock filesystem
     pub fn add_file<P: Into<PathBuf>>(&mut self, path: P, content: impl Into<String>) {
+        self.add_file_bytes(path, content.into().into_bytes());
+    }
+
+    /// Add a file with raw (possibly non-UTF-8) content to the mock filesystem
+    pub fn add_file_bytes<P: Into<PathBuf>>(&mut self, path: P, content: impl Into<Vec<u8>>) {
         let path = path.into();
         let content = content.into();
         let len = content.len() as u64;
@@ -97,6 +102,11 @@ impl Clone for MockFileSystem {
 
 impl FileReader for MockFileSystem {
     fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let content = self.read(path)?;
+        String::from_utf8(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
         // Check for configured errors first
         if let Some(error) = self.errors.get(path) {
             return Err(io::Error::new(error.kind(), error.to_string()));
@@ -147,6 +157,16 @@ mod tests {
         assert_eq!(fs.read_to_string(Path::new("test.txt")).unwrap(), "content");
     }
 
+    #[test]
+    fn test_mock_filesystem_read_bytes() {
+        let mut fs = MockFileSystem::new();
+        fs.add_file_bytes("latin1.txt", b"caf\xe9\n".to_vec());
+
+        assert_eq!(fs.read(Path::new("latin1.txt")).unwrap(), b"caf\xe9\n");
+        let result = fs.read_to_string(Path::new("latin1.txt"));
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
     #[test]
     fn test_mock_filesystem_file_not_found() {
         let fs = MockFileSystem::new();
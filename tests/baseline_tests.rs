@@ -0,0 +1,108 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_write_baseline_records_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("legacy.txt"), "old  \nno newline").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("legacy.txt")
+        .arg("--write-baseline")
+        .arg("lineguard-baseline.json");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote 2 issues to baseline"));
+
+    let baseline: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(temp_dir.path().join("lineguard-baseline.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(baseline["version"], 1);
+    let issues = baseline["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0]["file"], "legacy.txt");
+    assert_eq!(issues[0]["rule"], "missing_newline");
+    assert!(issues[0]["hash"].is_null());
+    assert_eq!(issues[1]["rule"], "trailing_space");
+    assert!(issues[1]["hash"].is_string());
+}
+
+#[test]
+fn test_baseline_hides_known_issues_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("legacy.txt");
+    fs::write(&file, "old  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("legacy.txt")
+        .arg("--write-baseline")
+        .arg("base.json");
+    cmd.assert().success();
+
+    // Known issue moves down a line: still baselined
+    fs::write(&file, "inserted\nold  \n").unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("legacy.txt").arg("--baseline").arg("base.json");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("All files passed"));
+
+    // A new issue is still reported
+    fs::write(&file, "new  \nold  \n").unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("legacy.txt")
+        .arg("--baseline")
+        .arg("base.json")
+        .arg("--format")
+        .arg("json");
+    let output = cmd.output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["total_issues"], 1);
+    assert_eq!(json["issues"][0]["issues"][0]["line"], 1);
+}
+
+#[test]
+fn test_baseline_reports_fixed_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("legacy.txt");
+    fs::write(&file, "one  \ntwo  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("legacy.txt")
+        .arg("--write-baseline")
+        .arg("base.json");
+    cmd.assert().success();
+
+    fs::write(&file, "one\ntwo  \n").unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("legacy.txt").arg("--baseline").arg("base.json");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "1 baselined issues have been fixed",
+    ));
+}
+
+#[test]
+fn test_invalid_baseline_is_a_configuration_error() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("file.txt"), "ok\n").unwrap();
+    fs::write(temp_dir.path().join("base.json"), "not json").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt").arg("--baseline").arg("base.json");
+
+    cmd.assert()
+        .code(4)
+        .stderr(predicate::str::contains("Error loading baseline"));
+}
@@ -0,0 +1,63 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_extensionless_binary_is_skipped() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("good.txt"), "content\n").unwrap();
+    std::fs::write(
+        temp_dir.path().join("a.out"),
+        b"\x7fELF\x02\x01\x01\x00\x00\x00  \nno newline",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg(".").arg("--recursive");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("All files passed"));
+}
+
+#[test]
+fn test_binary_detection_extension_only_reads_content() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("blob"), b"\x00\x01binary  \n").unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "binary_detection = \"extension\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("blob");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Trailing spaces found"));
+}
+
+#[test]
+fn test_binary_extensions_from_config() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("data.pb"), "text-looking  \n").unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "extend_binary_extensions = [\"pb\"]\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg(".").arg("--recursive").arg("--format").arg("json");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("data.pb").not());
+}
@@ -0,0 +1,81 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn setup(content: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nmax_consecutive_blank_lines = 1\nleading_blank_lines = true\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("a.txt"), content).unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_blank_lines_reported_with_ranges() {
+    let temp_dir = setup("\n\na\n\n\n\nb\n");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Lines 1-2: 2 blank lines at start of file [LG023]",
+        ))
+        .stdout(predicate::str::contains(
+            "Lines 4-6: 3 consecutive blank lines (max 1) [LG022]",
+        ));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--format").arg("github");
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "::error file=a.txt,line=4,endLine=6::3 consecutive blank lines (max 1) [LG022]",
+    ));
+}
+
+#[test]
+fn test_blank_lines_fixed() {
+    let temp_dir = setup("\n\na\r\n\r\n\r\n\r\nb\r\n");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--fix");
+    cmd.assert().success();
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("a.txt")).unwrap(),
+        "a\r\n\r\nb\r\n"
+    );
+}
+
+#[test]
+fn test_blank_lines_after_bom_reported_and_fixed() {
+    let temp_dir = setup("");
+    std::fs::write(temp_dir.path().join("a.txt"), b"\xEF\xBB\xBF\n\n\tfoo\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Lines 1-2: 2 blank lines at start of file [LG023]",
+        ))
+        .stdout(predicate::str::contains(
+            "Lines 1-2: 2 consecutive blank lines (max 1) [LG022]",
+        ));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--fix");
+    cmd.assert().success();
+
+    // Fixing leaves nothing for a second run to report
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert().success();
+}
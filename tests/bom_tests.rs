@@ -0,0 +1,100 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn write_config(temp_dir: &TempDir, bom: &str) {
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        format!("[checks]\nbom = \"{bom}\"\n"),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_forbidden_bom_is_reported_and_stripped() {
+    let temp_dir = TempDir::new().unwrap();
+    write_config(&temp_dir, "forbid");
+    let file = temp_dir.path().join("a.txt");
+    std::fs::write(&file, b"\xEF\xBB\xBFtext\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "UTF-8 byte order mark found [LG018]",
+    ));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--fix");
+    cmd.assert().success();
+    assert_eq!(std::fs::read(&file).unwrap(), b"text\n");
+}
+
+#[test]
+fn test_required_bom_is_reported_and_inserted() {
+    let temp_dir = TempDir::new().unwrap();
+    write_config(&temp_dir, "require");
+    let file = temp_dir.path().join("a.txt");
+    std::fs::write(&file, "text\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "Missing UTF-8 byte order mark [LG019]",
+    ));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--fix");
+    cmd.assert().success();
+    assert_eq!(std::fs::read(&file).unwrap(), b"\xEF\xBB\xBFtext\n");
+}
+
+#[test]
+fn test_utf16_file_is_reported_not_skipped() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("a.txt"),
+        b"\xFF\xFEt\x00e\x00x\x00t\x00\n\x00",
+    )
+    .unwrap();
+
+    // Reported under the default `bom = "ignore"` policy
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "UTF-16LE byte order mark found; file is not UTF-8 [LG020]",
+    ));
+
+    // Unless .editorconfig declares the file UTF-16
+    std::fs::write(
+        temp_dir.path().join(".editorconfig"),
+        "root = true\n\n[*.txt]\ncharset = utf-16le\n",
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert().success();
+}
+
+#[test]
+fn test_bom_follows_editorconfig_charset() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".editorconfig"),
+        "root = true\n\n[*]\ncharset = utf-8\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("a.txt"), b"\xEF\xBB\xBFtext\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[LG018]"));
+}
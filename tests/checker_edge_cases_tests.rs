@@ -90,6 +90,7 @@ fn test_check_file_with_disabled_checks() {
         checks: CheckConfig {
             newline_ending: false,
             trailing_spaces: false,
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -242,6 +243,7 @@ fn test_check_streaming_only_trailing_spaces_disabled() {
         checks: CheckConfig {
             newline_ending: true,
             trailing_spaces: false,
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -276,6 +278,7 @@ fn test_check_streaming_only_newline_disabled() {
         checks: CheckConfig {
             newline_ending: false,
             trailing_spaces: true,
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -296,3 +299,24 @@ fn test_check_streaming_only_newline_disabled() {
         .collect();
     assert!(newline_issues.is_empty());
 }
+
+#[test]
+fn test_check_file_legacy_encoding_with_require_utf8() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("cp1252.txt");
+
+    // Windows-1252 curly quotes are not valid UTF-8
+    fs::write(&file_path, b"say \x93hi\x94  \n").unwrap();
+
+    let mut config = Config::default();
+    let result = check_file(&file_path, &config);
+    assert!(result.error.is_none());
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].issue_type, IssueType::TrailingSpace);
+
+    config.checks.require_utf8 = true;
+    let result = check_file(&file_path, &config);
+    assert!(result.error.is_none());
+    assert_eq!(result.issues[0].issue_type, IssueType::InvalidUtf8);
+    assert!(result.issues[0].message.contains("byte offset 4"));
+}
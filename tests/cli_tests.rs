@@ -44,3 +44,69 @@ fn test_cli_shows_version() {
         .success()
         .stdout(predicate::str::contains("lineguard"));
 }
+
+#[test]
+fn test_cli_sarif_format() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("test.txt"), "trailing  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--format").arg("sarif");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let sarif: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let result = &sarif["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "LG003");
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "test.txt"
+    );
+}
+
+#[test]
+fn test_cli_junit_format() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("bad.txt"), "trailing  \n").unwrap();
+    std::fs::write(temp_dir.path().join("good.txt"), "clean\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("bad.txt")
+        .arg("good.txt")
+        .arg("--format")
+        .arg("junit");
+
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            r#"<testsuite name="lineguard" tests="2" failures="1" errors="0">"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#"<testcase name="good.txt" classname="lineguard"/>"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#"<failure type="trailing_space""#,
+        ))
+        .stdout(predicate::str::contains("Checking").not());
+}
+
+#[test]
+fn test_cli_github_format_underlines_trailing_run() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("test.txt"), "ok\nnaïve  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--format").arg("github");
+
+    cmd.assert().code(1).stdout(predicate::str::contains(
+        "::error file=test.txt,line=2,col=6,endColumn=8::Trailing spaces found [LG003]",
+    ));
+}
@@ -291,11 +291,22 @@ fn test_concurrent_directory_traversal() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let result = discover_files(&args, &Config::default()).unwrap();
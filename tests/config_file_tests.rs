@@ -140,3 +140,325 @@ ignore_patterns = []
         .success()
         .stdout(predicate::str::contains("\"files_checked\": 3")); // Both .txt and .rs, and .lineguardrc, but not ignore.txt
 }
+
+#[test]
+fn test_config_overrides_per_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let fixtures = temp_dir.path().join("tests").join("fixtures");
+    std::fs::create_dir_all(&fixtures).unwrap();
+
+    std::fs::write(temp_dir.path().join("README.md"), "hard break  \nend\n").unwrap();
+    std::fs::write(fixtures.join("sample.txt"), "no newline").unwrap();
+    std::fs::write(temp_dir.path().join("main.rs"), "fn main() {}  \n").unwrap();
+
+    let config_content = r#"
+[[overrides]]
+files = ["*.md"]
+checks = { trailing_spaces = false }
+
+[[overrides]]
+files = ["tests/fixtures/**"]
+checks = { newline_ending = false }
+"#;
+    std::fs::write(temp_dir.path().join(".lineguardrc"), config_content).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg(".").arg("--recursive");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("main.rs"))
+        .stdout(predicate::str::contains("README.md").not())
+        .stdout(predicate::str::contains("sample.txt").not());
+}
+
+#[test]
+fn test_config_overrides_later_wins() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("keep.md"), "text  \n").unwrap();
+    std::fs::write(temp_dir.path().join("other.md"), "text  \n").unwrap();
+
+    let config_content = r#"
+[[overrides]]
+files = ["*.md"]
+[overrides.checks]
+trailing_spaces = false
+
+[[overrides]]
+files = ["keep.md"]
+[overrides.checks]
+trailing_spaces = true
+line_ending = "crlf"
+"#;
+    std::fs::write(temp_dir.path().join(".lineguardrc"), config_content).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("keep.md").arg("other.md");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("keep.md"))
+        .stdout(predicate::str::contains("Trailing spaces found"))
+        .stdout(predicate::str::contains(
+            "LF line ending found (expected CRLF)",
+        ))
+        .stdout(predicate::str::contains("other.md").not());
+}
+
+#[test]
+fn test_config_overrides_can_disable_line_ending() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("script.bat"), "echo off\r\n").unwrap();
+    std::fs::write(temp_dir.path().join("main.rs"), "fn main() {}\r\n").unwrap();
+
+    let config_content = r#"
+[checks]
+line_ending = "lf"
+
+[[overrides]]
+files = ["*.bat"]
+checks = { line_ending = false }
+"#;
+    std::fs::write(temp_dir.path().join(".lineguardrc"), config_content).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("script.bat").arg("main.rs");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("main.rs"))
+        .stdout(predicate::str::contains("script.bat").not());
+}
+
+#[test]
+fn test_config_overrides_cli_flag_still_wins() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("file.txt"), "text  \n").unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[[overrides]]\nfiles = [\"*.txt\"]\nchecks = { trailing_spaces = true }\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt").arg("--no-trailing-space");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_config_overrides_reject_line_ending_true() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(temp_dir.path().join("file.txt"), "text\n").unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[[overrides]]\nfiles = [\"*.txt\"]\nchecks = { line_ending = true }\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt");
+
+    cmd.assert()
+        .code(4)
+        .stderr(predicate::str::contains("line_ending"));
+}
+
+#[test]
+fn test_editorconfig_settings_applied() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(
+        temp_dir.path().join(".editorconfig"),
+        "root = true\n\n[*.md]\ntrim_trailing_whitespace = false\n\n[*.txt]\nend_of_line = crlf\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("doc.md"), "hard break  \n").unwrap();
+    std::fs::write(temp_dir.path().join("file.txt"), "unix\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("doc.md").arg("file.txt");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("file.txt"))
+        .stdout(predicate::str::contains("doc.md").not());
+}
+
+#[test]
+fn test_explicit_checks_take_precedence_over_editorconfig() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(
+        temp_dir.path().join(".editorconfig"),
+        "root = true\n\n[*]\ntrim_trailing_whitespace = false\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("file.txt"), "text  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt");
+    cmd.assert().success();
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\ntrailing_spaces = true\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("file.txt"));
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "editorconfig = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt");
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_config_max_line_length() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nmax_line_length = 20\nline_length_ignore_pattern = \"^import \"\n\n[[overrides]]\nfiles = [\"*.md\"]\nchecks = { max_line_length = false }\n",
+    )
+    .unwrap();
+    let long = "a line that is longer than twenty columns\n";
+    std::fs::write(temp_dir.path().join("code.txt"), long).unwrap();
+    std::fs::write(temp_dir.path().join("doc.md"), long).unwrap();
+    std::fs::write(
+        temp_dir.path().join("imports.txt"),
+        "import some.very.long.module.name\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("code.txt").arg("doc.md").arg("imports.txt");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 1: Line too long (41 > 20 columns) [LG010]",
+        ))
+        .stdout(predicate::str::contains("doc.md").not())
+        .stdout(predicate::str::contains("imports.txt").not());
+
+    // The command line limit applies everywhere
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("doc.md").arg("--max-line-length").arg("50");
+    cmd.assert().success();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("doc.md").arg("--max-line-length").arg("30");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("doc.md"));
+}
+
+#[test]
+fn test_config_rejects_invalid_line_length_pattern() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nmax_line_length = 80\nline_length_ignore_pattern = \"(unclosed\"\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("file.txt"), "ok\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("file.txt");
+    cmd.assert()
+        .code(4)
+        .stderr(predicate::str::contains("regex parse error"));
+}
+
+#[test]
+fn test_config_indent_style_with_makefile_override() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nindent_style = \"spaces\"\nindent_size = 2\n\n[[overrides]]\nfiles = [\"Makefile\", \"*.mk\"]\nchecks = { indent_style = \"tabs\" }\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("main.py"), "if x:\n\treturn 1\n").unwrap();
+    std::fs::write(temp_dir.path().join("Makefile"), "all:\n  build\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("main.py").arg("Makefile");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 2: Tab indentation found (expected spaces) [LG011]",
+        ))
+        .stdout(predicate::str::contains(
+            "Line 2: Space indentation found (expected tabs) [LG012]",
+        ));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("main.py").arg("Makefile").arg("--fix");
+    cmd.assert().success();
+
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("main.py")).unwrap(),
+        // Tabs expand to `tab_width`, not `indent_size`
+        "if x:\n    return 1\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("Makefile")).unwrap(),
+        "all:\n\tbuild\n"
+    );
+}
+
+#[test]
+fn test_overrides_built_without_a_config_file() {
+    use lineguard::config::{CheckOverrides, Config, Override};
+    use std::path::Path;
+
+    let mut config = Config::default();
+    config.overrides.push(Override::new(
+        vec!["*.md".to_string()],
+        CheckOverrides {
+            trailing_spaces: Some(false),
+            ..Default::default()
+        },
+    ));
+    assert!(!config.checks_for(Path::new("docs/a.md")).trailing_spaces);
+    assert!(config.checks_for(Path::new("a.rs")).trailing_spaces);
+
+    // Deserialized directly rather than loaded from a file
+    let config: Config = toml::from_str(
+        "[[overrides]]\nfiles = [\"*.md\"]\n[overrides.checks]\ntrailing_spaces = false\n",
+    )
+    .unwrap();
+    assert!(!config.checks_for(Path::new("a.md")).trailing_spaces);
+}
@@ -0,0 +1,45 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_conflict_markers_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("lib.rs"),
+        "fn main() {\n<<<<<<< HEAD\n    a();\n=======\n    b();\n>>>>>>> topic\n}\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("lib.rs");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 2: Merge conflict marker found (<<<<<<<) [LG021]",
+        ))
+        .stdout(predicate::str::contains(
+            "Line 4: Merge conflict marker found (=======) [LG021]",
+        ))
+        .stdout(predicate::str::contains(
+            "Line 6: Merge conflict marker found (>>>>>>>) [LG021]",
+        ));
+}
+
+#[test]
+fn test_setext_headings_are_not_conflict_markers() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("README.md"),
+        "Title\n=====\n\nLonger title\n============\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("README.md");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("All files passed"));
+}
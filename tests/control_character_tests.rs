@@ -0,0 +1,54 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_control_characters_reported_with_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("build.log"),
+        "ok\n\x1b[32mpassed\x1b[0m\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("build.log").arg("--format").arg("github");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "::error file=build.log,line=2,col=1,endColumn=2::U+001B ESCAPE found (2 occurrences) [LG025]",
+        ))
+        .stdout(predicate::str::contains("line=2,col=12,").not());
+}
+
+#[test]
+fn test_form_feeds_allowed_by_default_and_fix() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nfix_control_characters = true\n",
+    )
+    .unwrap();
+    let file = temp_dir.path().join("main.c");
+    std::fs::write(&file, "int a;\n\x0c\nint b;\x07\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("main.c");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 3: U+0007 ALERT found [LG025]",
+        ))
+        .stdout(predicate::str::contains("Line 2:").not());
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("main.c").arg("--fix");
+    cmd.assert().success();
+    assert_eq!(
+        std::fs::read_to_string(&file).unwrap(),
+        "int a;\n\x0c\nint b;\n"
+    );
+}
@@ -32,11 +32,22 @@ fn test_discover_files_from_args() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -65,11 +76,22 @@ fn test_discover_files_with_extensions_from_cli() {
         extensions: Some(vec!["txt".to_string(), "rs".to_string()]),
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -102,11 +124,22 @@ fn test_discover_files_with_ignore_patterns_from_cli() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -137,11 +170,22 @@ fn test_discover_files_with_invalid_glob_pattern() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -172,11 +216,22 @@ fn test_discover_files_glob_no_matches_then_literal() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -307,11 +362,22 @@ fn test_discover_directory_read_error() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -351,11 +417,22 @@ fn test_discover_files_recursive_with_ignored_directory() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -387,11 +464,22 @@ fn test_is_ignored_with_parent_directory_pattern() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -427,11 +515,22 @@ fn test_is_ignored_with_relative_path_pattern() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
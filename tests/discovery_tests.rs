@@ -22,11 +22,22 @@ fn test_discover_single_file() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -63,11 +74,22 @@ fn test_discover_multiple_files() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -103,11 +125,22 @@ fn test_discover_files_with_glob_pattern() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -155,11 +188,22 @@ fn test_discover_files_in_directory() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -196,11 +240,22 @@ fn test_discover_files_recursive() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -28,11 +28,22 @@ fn test_discover_files_with_symlink() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -69,11 +80,22 @@ fn test_discover_files_with_symlink_directory() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -106,11 +128,22 @@ fn test_discover_files_with_broken_symlink() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -147,11 +180,22 @@ fn test_discover_files_with_circular_symlink() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -190,11 +234,22 @@ fn test_discover_symlink_in_recursive_mode() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
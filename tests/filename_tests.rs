@@ -0,0 +1,103 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn setup() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nportable_filenames = true\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_non_portable_filenames_reported() {
+    let temp_dir = setup();
+    for name in ["aux.c", "notes.", "a:b.txt", "cafe\u{301}.txt", "ok.txt"] {
+        std::fs::write(temp_dir.path().join(name), "text\n").unwrap();
+    }
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("--recursive").arg(".");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "\"aux.c\" is a reserved name on Windows [LG026]",
+        ))
+        .stdout(predicate::str::contains(
+            "\"notes.\" ends with a dot or space, which Windows strips [LG027]",
+        ))
+        .stdout(predicate::str::contains(
+            "\"a:b.txt\" contains U+003A, which is not allowed in Windows file names [LG028]",
+        ))
+        .stdout(predicate::str::contains(
+            "is not in Unicode normalization form C [LG030]",
+        ))
+        .stdout(predicate::str::contains("ok.txt").not());
+}
+
+#[test]
+fn test_case_collisions_reported_for_each_path() {
+    let temp_dir = setup();
+    std::fs::write(temp_dir.path().join("README.md"), "text\n").unwrap();
+    std::fs::write(temp_dir.path().join("readme.md"), "text\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("README.md")
+        .arg("readme.md")
+        .arg("--format")
+        .arg("github");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "::error file=README.md::\"README.md\" differs only in case from \"readme.md\" [LG029]",
+        ))
+        .stdout(predicate::str::contains(
+            "::error file=readme.md::\"readme.md\" differs only in case from \"README.md\" [LG029]",
+        ));
+}
+
+#[test]
+fn test_case_collisions_with_unchecked_siblings() {
+    let temp_dir = setup();
+    std::fs::write(temp_dir.path().join("README.md"), "text\n").unwrap();
+    std::fs::write(temp_dir.path().join("readme.md"), "text\n").unwrap();
+    if !temp_dir.path().join("README.md").exists()
+        || std::fs::read_dir(&temp_dir).unwrap().count() < 3
+    {
+        // Case-insensitive file system
+        return;
+    }
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("README.md").arg("--format").arg("github");
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "::error file=README.md::\"README.md\" differs only in case from \"readme.md\" [LG029]",
+    ));
+}
+
+#[test]
+fn test_filename_checks_are_opt_in_and_selectable() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("aux.c"), "int x;\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("aux.c");
+    cmd.assert().success();
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "ignore = [\"reserved-filename\"]\n\n[checks]\nportable_filenames = true\n",
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("aux.c");
+    cmd.assert().success();
+}
@@ -49,3 +49,36 @@ fn test_should_skip_common_binary_extensions() {
     assert!(should_check_file(Path::new("readme.md"), &config));
     assert!(should_check_file(Path::new("config.toml"), &config));
 }
+
+#[test]
+fn test_extend_binary_extensions() {
+    let config = Config {
+        extend_binary_extensions: vec!["pb".to_string()],
+        ..Default::default()
+    };
+
+    assert!(!should_check_file(Path::new("model.pb"), &config));
+    assert!(!should_check_file(Path::new("image.png"), &config));
+    assert!(should_check_file(Path::new("code.rs"), &config));
+}
+
+#[test]
+fn test_override_binary_extensions() {
+    let config = Config {
+        binary_extensions: Some(vec!["pb".to_string()]),
+        ..Default::default()
+    };
+
+    assert!(!should_check_file(Path::new("model.PB"), &config));
+    assert!(should_check_file(Path::new("image.svg"), &config));
+}
+
+#[test]
+fn test_content_only_binary_detection_ignores_extensions() {
+    let config = Config {
+        binary_detection: lineguard::config::BinaryDetection::Content,
+        ..Default::default()
+    };
+
+    assert!(should_check_file(Path::new("image.png"), &config));
+}
@@ -185,3 +185,63 @@ fn test_fix_no_issues() {
         .success()
         .stdout(predicate::str::contains("Fixed").not());
 }
+
+#[test]
+fn test_fix_preserves_crlf_line_endings() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("build.bat");
+
+    std::fs::write(&file_path, "@echo off  \r\necho done\r\n\r\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("build.bat");
+    cmd.arg("--fix");
+
+    cmd.assert().success();
+
+    let content = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(content, "@echo off\r\necho done\r\n");
+}
+
+#[test]
+fn test_fix_normalizes_to_configured_line_ending() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("script.sh");
+
+    std::fs::write(&file_path, "#!/bin/sh\r\necho hi  \r\necho bye\n").unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nline_ending = \"lf\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("script.sh");
+    cmd.arg("--fix");
+
+    cmd.assert().success();
+
+    let content = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(content, "#!/bin/sh\necho hi\necho bye\n");
+}
+
+#[test]
+fn test_fix_legacy_encoded_file_without_reencoding() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("latin1.txt");
+
+    // Latin-1 "café" and "naïve" with trailing whitespace and no final newline
+    std::fs::write(&file_path, b"caf\xe9  \nna\xefve\t").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("latin1.txt");
+    cmd.arg("--fix");
+
+    cmd.assert().success();
+
+    let content = std::fs::read(&file_path).unwrap();
+    assert_eq!(content, b"caf\xe9\nna\xefve\n");
+}
@@ -1,4 +1,4 @@
-use lineguard::checker::{Issue, IssueType};
+use lineguard::checker::{Issue, IssueType, Severity};
 use lineguard::config::{CheckConfig, Config};
 use lineguard::fixer::fix_file;
 use std::fs;
@@ -23,11 +23,19 @@ fn test_fix_large_file_streaming() {
         Issue {
             issue_type: IssueType::TrailingSpace,
             line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
             message: "Trailing spaces found".to_string(),
         },
         Issue {
             issue_type: IssueType::MissingNewline,
             line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
             message: "Missing newline at end of file".to_string(),
         },
     ];
@@ -59,6 +67,10 @@ fn test_fix_large_file_dry_run() {
     let issues = vec![Issue {
         issue_type: IssueType::TrailingSpace,
         line: None,
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Trailing spaces found".to_string(),
     }];
 
@@ -108,6 +120,10 @@ fn test_fix_streaming_only_trailing_spaces() {
     let issues = vec![Issue {
         issue_type: IssueType::TrailingSpace,
         line: None,
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Trailing spaces found".to_string(),
     }];
 
@@ -121,6 +137,39 @@ fn test_fix_streaming_only_trailing_spaces() {
     assert!(!fixed_content.contains("    \n"));
 }
 
+#[test]
+fn test_fix_streaming_only_reported_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let large_file = temp_dir.path().join("large_reported.txt");
+
+    // Large enough to stream; only line 2 has an issue with a line number
+    let mut content = String::new();
+    for i in 0..500000 {
+        content.push_str(&format!("Line {i} with spaces    \n"));
+    }
+    fs::write(&large_file, &content).unwrap();
+
+    let issues = vec![Issue {
+        issue_type: IssueType::TrailingSpace,
+        line: Some(2),
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
+        message: "Trailing spaces found".to_string(),
+    }];
+
+    let config = Config::default();
+    let result = fix_file(&large_file, &issues, &config, false).unwrap();
+    assert!(result.fixed);
+
+    let fixed_content = fs::read_to_string(&large_file).unwrap();
+    let mut lines = fixed_content.lines();
+    assert_eq!(lines.next(), Some("Line 0 with spaces    "));
+    assert_eq!(lines.next(), Some("Line 1 with spaces"));
+    assert_eq!(lines.next(), Some("Line 2 with spaces    "));
+}
+
 #[test]
 fn test_fix_streaming_only_newline_issues() {
     let temp_dir = TempDir::new().unwrap();
@@ -137,6 +186,10 @@ fn test_fix_streaming_only_newline_issues() {
     let issues = vec![Issue {
         issue_type: IssueType::MultipleNewlines,
         line: None,
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Multiple trailing newlines".to_string(),
     }];
 
@@ -162,11 +215,19 @@ fn test_fix_with_disabled_checks() {
         Issue {
             issue_type: IssueType::TrailingSpace,
             line: Some(1),
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
             message: "Trailing spaces found".to_string(),
         },
         Issue {
             issue_type: IssueType::MissingNewline,
             line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
             message: "Missing newline at end of file".to_string(),
         },
     ];
@@ -176,6 +237,7 @@ fn test_fix_with_disabled_checks() {
         checks: CheckConfig {
             newline_ending: false,
             trailing_spaces: false,
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -206,6 +268,10 @@ fn test_fix_streaming_empty_lines_at_end() {
     let issues = vec![Issue {
         issue_type: IssueType::MultipleNewlines,
         line: None,
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Multiple trailing newlines".to_string(),
     }];
 
@@ -238,6 +304,10 @@ fn test_fix_with_write_error() {
     let issues = vec![Issue {
         issue_type: IssueType::TrailingSpace,
         line: Some(1),
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Trailing spaces found".to_string(),
     }];
 
@@ -292,6 +362,10 @@ fn test_apply_fixes_with_partial_config() {
     let issues = vec![Issue {
         issue_type: IssueType::TrailingSpace,
         line: Some(1),
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Trailing spaces found".to_string(),
     }];
 
@@ -313,6 +387,10 @@ fn test_fix_streaming_io_error() {
     let issues = vec![Issue {
         issue_type: IssueType::TrailingSpace,
         line: Some(1),
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: "Trailing spaces found".to_string(),
     }];
 
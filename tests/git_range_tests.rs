@@ -313,3 +313,122 @@ fn test_verbose_mode_shows_git_range_info() {
         .stdout(predicate::str::contains("file5.txt"))
         .stdout(predicate::str::contains("file6.txt"));
 }
+
+#[test]
+fn test_changed_lines_only_ignores_preexisting_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir).unwrap();
+
+    let first_commit = create_commit(
+        &temp_dir,
+        &[("legacy.txt", "old 1  \nold 2\nold 3  \n")],
+        "Legacy file",
+    )
+    .unwrap();
+
+    create_commit(
+        &temp_dir,
+        &[("legacy.txt", "old 1  \nnew 2  \nold 3  \n")],
+        "Touch one line",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("--from")
+        .arg(&first_commit)
+        .arg("--changed-lines-only");
+    cmd.arg("--format").arg("json").arg(".");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issues = json["issues"][0]["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["line"], 2);
+}
+
+#[test]
+fn test_changed_lines_only_fix_leaves_unchanged_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir).unwrap();
+
+    let first_commit = create_commit(
+        &temp_dir,
+        &[("legacy.txt", "old  \nx\nold 3\n")],
+        "Legacy file",
+    )
+    .unwrap();
+
+    create_commit(
+        &temp_dir,
+        &[("legacy.txt", "old  \nx\nnew  \n")],
+        "Touch one line",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("--from")
+        .arg(&first_commit)
+        .arg("--changed-lines-only")
+        .arg("--fix")
+        .arg(".");
+    cmd.assert().success();
+
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("legacy.txt")).unwrap(),
+        "old  \nx\nnew\n"
+    );
+}
+
+#[test]
+fn test_changed_lines_only_file_level_issue_needs_last_line_change() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir).unwrap();
+
+    let first_commit = create_commit(
+        &temp_dir,
+        &[
+            ("untouched_end.txt", "a\nb\nc"),
+            ("touched_end.txt", "a\nb\nc\n"),
+        ],
+        "Initial commit",
+    )
+    .unwrap();
+
+    create_commit(
+        &temp_dir,
+        &[
+            ("untouched_end.txt", "A\nb\nc"),
+            ("touched_end.txt", "a\nb\nc\nd"),
+        ],
+        "Edit files",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("--from")
+        .arg(&first_commit)
+        .arg("--changed-lines-only");
+    cmd.arg(".");
+
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains("touched_end.txt"))
+        .stdout(predicate::str::contains("untouched_end.txt").not());
+}
+
+#[test]
+fn test_changed_lines_only_requires_from() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("file.txt"), "content\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("--changed-lines-only").arg("file.txt");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--from"));
+}
@@ -0,0 +1,134 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_gitignore_with_negation() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::write(root.join(".gitignore"), "*.txt\n!keep.txt\ntarget/\n").unwrap();
+    fs::write(root.join("skip.txt"), "bad  ").unwrap();
+    fs::write(root.join("keep.txt"), "kept  \n").unwrap();
+    fs::create_dir(root.join("target")).unwrap();
+    fs::write(root.join("target").join("out.rs"), "bad  ").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg(".").arg("--recursive");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("keep.txt"))
+        .stdout(predicate::str::contains("skip.txt").not())
+        .stdout(predicate::str::contains("out.rs").not());
+}
+
+#[test]
+fn test_nested_ignore_and_lineguardignore() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let sub = root.join("sub");
+    fs::create_dir(&sub).unwrap();
+
+    fs::write(root.join(".lineguardignore"), "generated.rs\n").unwrap();
+    fs::write(sub.join(".ignore"), "*.md\n").unwrap();
+    fs::write(root.join("generated.rs"), "bad  ").unwrap();
+    fs::write(root.join("README.md"), "bad  ").unwrap();
+    fs::write(sub.join("notes.md"), "bad  ").unwrap();
+    fs::write(sub.join("generated.rs"), "bad  ").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg(".").arg("--recursive");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("README.md"))
+        .stdout(predicate::str::contains("notes.md").not())
+        .stdout(predicate::str::contains("generated.rs").not());
+}
+
+#[test]
+fn test_git_info_exclude_and_git_dir_skipped() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let info = root.join(".git").join("info");
+    fs::create_dir_all(&info).unwrap();
+
+    fs::write(info.join("exclude"), "local.txt\n").unwrap();
+    fs::write(root.join(".git").join("config"), "bad  ").unwrap();
+    fs::write(root.join("local.txt"), "bad  ").unwrap();
+    fs::write(root.join("good.txt"), "good\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg(".").arg("--recursive");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("All files passed"));
+}
+
+#[test]
+fn test_no_ignore_vcs_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::write(root.join(".gitignore"), "vcs.txt\n").unwrap();
+    fs::write(root.join(".lineguardignore"), "lineguard.txt\n").unwrap();
+    fs::write(root.join("vcs.txt"), "bad  ").unwrap();
+    fs::write(root.join("lineguard.txt"), "bad  ").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg(".").arg("--recursive").arg("--no-ignore-vcs");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("vcs.txt"))
+        .stdout(predicate::str::contains("lineguard.txt").not());
+}
+
+#[test]
+fn test_explicit_file_not_filtered_by_gitignore() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::write(root.join(".gitignore"), "*.txt\n").unwrap();
+    fs::write(root.join("explicit.txt"), "bad  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg("explicit.txt");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("explicit.txt"));
+}
+
+#[test]
+fn test_walk_starting_inside_ignored_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join(".git")).unwrap();
+    fs::create_dir(root.join("build")).unwrap();
+
+    fs::write(root.join(".gitignore"), "build/\n").unwrap();
+    fs::write(root.join("build").join("out.rs"), "bad  ").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg("build/").arg("--recursive");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("out.rs").not());
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(root);
+    cmd.arg("build/").arg("--recursive").arg("--no-ignore-vcs");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("out.rs"));
+}
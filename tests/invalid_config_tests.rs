@@ -28,6 +28,33 @@ newline_ending = true
         .stderr(predicate::str::contains("Error loading configuration"));
 }
 
+#[test]
+fn test_invalid_override_pattern() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join(".lineguardrc");
+
+    std::fs::write(
+        &config_path,
+        r#"
+[[overrides]]
+files = ["src/[a-"]
+checks = { trailing_spaces = false }
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.arg(".")
+        .arg("--config")
+        .arg(&config_path)
+        .assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "Invalid pattern \"src/[a-\" in [[overrides]] files",
+        ));
+}
+
 #[test]
 fn test_unknown_config_fields() {
     let temp_dir = TempDir::new().unwrap();
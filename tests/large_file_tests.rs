@@ -75,3 +75,29 @@ fn test_memory_efficient_processing() {
     // Should complete successfully without running out of memory
     cmd.assert().success();
 }
+
+#[test]
+fn test_fix_large_file_preserves_crlf() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("large.txt");
+
+    // Create a file over 10MB so the streaming fixer is used
+    let mut content = String::with_capacity(11 * 1024 * 1024);
+    while content.len() < 11 * 1024 * 1024 {
+        content.push_str("Line with trailing spaces and CRLF  \r\n");
+    }
+    content.push_str("last line");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("large.txt");
+    cmd.arg("--fix");
+
+    cmd.assert().success();
+
+    let fixed_content = std::fs::read_to_string(&file_path).unwrap();
+    assert!(!fixed_content.contains("  \r\n"));
+    assert!(!fixed_content.replace("\r\n", "").contains('\n'));
+    assert!(fixed_content.ends_with("last line\r\n"));
+}
@@ -0,0 +1,97 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn write_config(temp_dir: &TempDir, style: &str) -> std::path::PathBuf {
+    let config_path = temp_dir.path().join("lineguard.toml");
+    std::fs::write(
+        &config_path,
+        format!("[checks]\nline_ending = \"{style}\"\n"),
+    )
+    .unwrap();
+    config_path
+}
+
+#[test]
+fn test_crlf_reported_in_lf_mode() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("script.sh");
+    std::fs::write(&file_path, "#!/bin/sh\r\necho hi\r\n").unwrap();
+    let config_path = write_config(&temp_dir, "lf");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.arg(&file_path).arg("--config").arg(&config_path);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 1: CRLF line ending found (expected LF)",
+        ))
+        .stdout(predicate::str::contains(
+            "Line 2: CRLF line ending found (expected LF)",
+        ));
+}
+
+#[test]
+fn test_mixed_line_endings_reported_in_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed.txt");
+    std::fs::write(&file_path, "one\r\ntwo\nthree\r\n").unwrap();
+    let config_path = write_config(&temp_dir, "consistent");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.arg(&file_path)
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("\"type\": \"mixed_line_endings\""))
+        .stdout(predicate::str::contains("\"line\": 2"));
+}
+
+#[test]
+fn test_lone_cr_reported_in_github_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("lone.txt");
+    std::fs::write(&file_path, "one\ntwo\rthree\n").unwrap();
+    let config_path = write_config(&temp_dir, "lf");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.arg(&file_path)
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--format")
+        .arg("github");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(",line=2::Lone carriage return"));
+}
+
+#[test]
+fn test_line_endings_not_checked_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed.txt");
+    std::fs::write(&file_path, "one\r\ntwo\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path()).arg(&file_path);
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_invalid_line_ending_style_is_config_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("file.txt");
+    std::fs::write(&file_path, "one\n").unwrap();
+    let config_path = write_config(&temp_dir, "mac");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.arg(&file_path).arg("--config").arg(&config_path);
+
+    cmd.assert().code(4);
+}
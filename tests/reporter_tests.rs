@@ -1,4 +1,4 @@
-use lineguard::checker::{CheckResult, Issue, IssueType};
+use lineguard::checker::{CheckResult, Issue, IssueType, Severity};
 // Reporter imports are now handled through test_utils module
 use std::path::PathBuf;
 
@@ -19,11 +19,19 @@ fn test_github_reporter_with_issues() {
                 Issue {
                     issue_type: IssueType::MissingNewline,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Missing newline at end of file".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(45),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Trailing spaces found".to_string(),
                 },
             ],
@@ -34,6 +42,10 @@ fn test_github_reporter_with_issues() {
             issues: vec![Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(23),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Trailing spaces found".to_string(),
             }],
             error: None,
@@ -137,11 +149,19 @@ fn test_json_reporter_with_issues() {
                 Issue {
                     issue_type: IssueType::MissingNewline,
                     line: None,
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Missing newline at end of file".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(45),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Trailing spaces found".to_string(),
                 },
             ],
@@ -152,6 +172,10 @@ fn test_json_reporter_with_issues() {
             issues: vec![Issue {
                 issue_type: IssueType::MultipleNewlines,
                 line: Some(100),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Multiple trailing newlines".to_string(),
             }],
             error: None,
@@ -265,6 +289,10 @@ fn test_json_reporter_with_errors() {
             issues: vec![Issue {
                 issue_type: IssueType::MissingNewline,
                 line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Missing newline at end of file".to_string(),
             }],
             error: None,
@@ -366,6 +394,10 @@ fn test_human_reporter_with_color_disabled() {
         issues: vec![Issue {
             issue_type: IssueType::MissingNewline,
             line: None,
+            end_line: None,
+            column: None,
+            end_column: None,
+            severity: Severity::Error,
             message: "Missing newline at end of file".to_string(),
         }],
         error: None,
@@ -421,11 +453,19 @@ fn test_human_reporter_with_color_enabled() {
             Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(15),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Trailing spaces found".to_string(),
             },
             Issue {
                 issue_type: IssueType::MultipleNewlines,
                 line: Some(42),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Multiple trailing newlines".to_string(),
             },
         ],
@@ -569,6 +609,10 @@ fn test_human_reporter_multiple_files_with_issues() {
             issues: vec![Issue {
                 issue_type: IssueType::MissingNewline,
                 line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Missing newline at end of file".to_string(),
             }],
             error: None,
@@ -579,11 +623,19 @@ fn test_human_reporter_multiple_files_with_issues() {
                 Issue {
                     issue_type: IssueType::TrailingSpace,
                     line: Some(10),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Trailing spaces found".to_string(),
                 },
                 Issue {
                     issue_type: IssueType::MultipleNewlines,
                     line: Some(25),
+                    end_line: None,
+                    column: None,
+                    end_column: None,
+                    severity: Severity::Error,
                     message: "Multiple trailing newlines".to_string(),
                 },
             ],
@@ -669,16 +721,28 @@ fn test_all_issue_types_in_json() {
             Issue {
                 issue_type: IssueType::MissingNewline,
                 line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Missing newline".to_string(),
             },
             Issue {
                 issue_type: IssueType::MultipleNewlines,
                 line: Some(50),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Multiple newlines".to_string(),
             },
             Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(25),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Trailing space".to_string(),
             },
         ],
@@ -756,16 +820,28 @@ fn test_github_reporter_all_line_scenarios() {
             Issue {
                 issue_type: IssueType::MissingNewline,
                 line: None,
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "No line number".to_string(),
             },
             Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(1),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Line 1".to_string(),
             },
             Issue {
                 issue_type: IssueType::TrailingSpace,
                 line: Some(999),
+                end_line: None,
+                column: None,
+                end_column: None,
+                severity: Severity::Error,
                 message: "Line 999".to_string(),
             },
         ],
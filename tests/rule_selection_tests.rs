@@ -0,0 +1,100 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn write_sample(temp_dir: &TempDir) {
+    // Trailing spaces (LG003) and a missing final newline (LG001)
+    std::fs::write(temp_dir.path().join("test.txt"), "line 1  \nline 2").unwrap();
+}
+
+#[test]
+fn test_select_reports_only_selected_rules() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--select").arg("LG003");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Trailing spaces found [LG003]"))
+        .stdout(predicate::str::contains("Missing newline").not());
+}
+
+#[test]
+fn test_extend_select_and_ignore_rule() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt")
+        .arg("--select")
+        .arg("trailing-space")
+        .arg("--extend-select")
+        .arg("missing_newline");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[LG003]"))
+        .stdout(predicate::str::contains("[LG001]"));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--ignore-rule").arg("LG001,LG003");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_config_select_and_ignore() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "select = [\"ALL\"]\nignore = [\"trailing-space\"]\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[LG001]"))
+        .stdout(predicate::str::contains("[LG003]").not());
+
+    // --select on the command line replaces the configured selection
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--select").arg("LG003");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_unknown_rule_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--select").arg("LG999");
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("unknown rule `LG999`"));
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "ignore = [\"no-such-rule\"]\n",
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt");
+    cmd.assert()
+        .code(4)
+        .stderr(predicate::str::contains("unknown rule `no-such-rule`"));
+}
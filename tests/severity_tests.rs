@@ -0,0 +1,117 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn write_sample(temp_dir: &TempDir) {
+    // Trailing spaces (LG003) and a missing final newline (LG001)
+    std::fs::write(temp_dir.path().join("test.txt"), "line 1  \nline 2").unwrap();
+}
+
+fn write_config(temp_dir: &TempDir, config: &str) {
+    std::fs::write(temp_dir.path().join(".lineguardrc"), config).unwrap();
+}
+
+#[test]
+fn test_warnings_do_not_fail_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+    write_config(&temp_dir, "[severity]\nALL = \"warning\"\n");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "  warning Line 1: Trailing spaces found [LG003]",
+        ))
+        .stdout(predicate::str::contains(
+            "  warning Missing newline at end of file [LG001]",
+        ));
+}
+
+#[test]
+fn test_fail_on_threshold() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+    write_config(
+        &temp_dir,
+        "[severity]\nALL = \"info\"\ntrailing-space = \"warning\"\n",
+    );
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--fail-on").arg("warning");
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains("  warning Line 1"))
+        .stdout(predicate::str::contains("  info    Missing newline"));
+
+    // With trailing spaces ignored only an info issue is left
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt")
+        .arg("--fail-on")
+        .arg("warning")
+        .arg("--ignore-rule")
+        .arg("LG003");
+    cmd.assert().success();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt")
+        .arg("--fail-on")
+        .arg("info")
+        .arg("--ignore-rule")
+        .arg("LG003");
+    cmd.assert().code(1);
+}
+
+#[test]
+fn test_severity_in_machine_readable_formats() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+    write_config(
+        &temp_dir,
+        "[severity]\nLG001 = \"info\"\nLG003 = \"warning\"\n",
+    );
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--format").arg("github");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "::warning file=test.txt,line=1,col=7,endColumn=9::",
+        ))
+        .stdout(predicate::str::contains("::notice file=test.txt::"));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt").arg("--format").arg("json");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issues = json["issues"][0]["issues"].as_array().unwrap();
+    let level = |code: &str| {
+        issues
+            .iter()
+            .find(|issue| issue["code"] == code)
+            .map(|issue| issue["level"].clone())
+            .unwrap()
+    };
+    assert_eq!(level("LG001"), "info");
+    assert_eq!(level("LG003"), "warning");
+}
+
+#[test]
+fn test_invalid_severity_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    write_sample(&temp_dir);
+    write_config(&temp_dir, "[severity]\nLG003 = \"fatal\"\n");
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("test.txt");
+    cmd.assert().code(4);
+}
@@ -0,0 +1,140 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+fn git(repo: &Path, args: &[&str]) {
+    let output = StdCommand::new("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn init_git_repo(dir: &TempDir) {
+    git(dir.path(), &["init"]);
+    git(dir.path(), &["config", "user.name", "Test User"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "commit.gpgsign", "false"]);
+}
+
+#[test]
+fn test_staged_checks_index_content_not_working_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir);
+
+    // Stage a bad version, then clean up the working tree without staging
+    std::fs::write(temp_dir.path().join("file.txt"), "bad  \n").unwrap();
+    git(temp_dir.path(), &["add", "file.txt"]);
+    std::fs::write(temp_dir.path().join("file.txt"), "good\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--staged");
+
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains("file.txt"))
+        .stdout(predicate::str::contains("Trailing spaces found"));
+}
+
+#[test]
+fn test_staged_large_file_final_newline_from_index() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir);
+
+    // Over the streaming threshold; only the working copy lacks a final newline
+    let staged = "line of text\n".repeat(1024 * 1024);
+    std::fs::write(temp_dir.path().join("large.txt"), &staged).unwrap();
+    git(temp_dir.path(), &["add", "large.txt"]);
+    std::fs::write(temp_dir.path().join("large.txt"), staged.trim_end()).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--staged");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Missing newline").not());
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("large.txt");
+    cmd.assert().code(1).stdout(predicate::str::contains(
+        "Missing newline at end of file [LG001]",
+    ));
+}
+
+#[test]
+fn test_staged_ignores_unstaged_files() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir);
+
+    std::fs::write(temp_dir.path().join("staged.txt"), "good\n").unwrap();
+    std::fs::write(temp_dir.path().join("unstaged.txt"), "bad  ").unwrap();
+    git(temp_dir.path(), &["add", "staged.txt"]);
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--staged");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("unstaged.txt").not());
+}
+
+#[test]
+fn test_staged_fix_restages_fully_staged_file() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir);
+
+    std::fs::write(temp_dir.path().join("file.txt"), "bad  \n").unwrap();
+    git(temp_dir.path(), &["add", "file.txt"]);
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--staged").arg("--fix");
+    cmd.assert().success();
+
+    let staged = StdCommand::new("git")
+        .args(["cat-file", "blob", ":file.txt"])
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(staged.stdout, b"bad\n");
+}
+
+#[test]
+fn test_staged_fix_refuses_partially_staged_file() {
+    let temp_dir = TempDir::new().unwrap();
+    init_git_repo(&temp_dir);
+
+    std::fs::write(temp_dir.path().join("file.txt"), "bad  \n").unwrap();
+    git(temp_dir.path(), &["add", "file.txt"]);
+    std::fs::write(temp_dir.path().join("file.txt"), "bad  \nunstaged\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--staged").arg("--fix");
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("partially staged"));
+
+    let content = std::fs::read_to_string(temp_dir.path().join("file.txt")).unwrap();
+    assert_eq!(content, "bad  \nunstaged\n");
+}
+
+#[test]
+fn test_staged_conflicts_with_stdin() {
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.arg("--staged").arg("--stdin");
+
+    cmd.assert().failure();
+}
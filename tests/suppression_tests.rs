@@ -0,0 +1,90 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_suppressed_lines_are_not_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("fixture.md"),
+        "<!-- lineguard-disable-next-line trailing-space -->\n\
+         hard break  \n\
+         // lineguard-disable\n\
+         block  \n\
+         // lineguard-enable\n\
+         reported  \n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("fixture.md").arg("--format").arg("json");
+
+    let output = cmd.output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = json["issues"][0]["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["line"], 6);
+}
+
+#[test]
+fn test_disable_file_suppresses_file_level_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("fixture.txt"),
+        "# lineguard-disable-file\ntrailing  \nno newline",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("fixture.txt");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("All files passed"));
+}
+
+#[test]
+fn test_fix_leaves_suppressed_lines_untouched() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("fixture.txt");
+    fs::write(
+        &file,
+        "fixed  \nkept  # lineguard-disable-line trailing-space\nfixed\t\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("fixture.txt").arg("--fix");
+    cmd.assert().success();
+
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        "fixed\nkept  # lineguard-disable-line trailing-space\nfixed\n"
+    );
+}
+
+#[test]
+fn test_report_unused_suppressions() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("fixture.txt"),
+        "clean # lineguard-disable-line trailing-space\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("fixture.txt");
+    cmd.assert().success();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("fixture.txt").arg("--report-unused-suppressions");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Unused suppression"));
+}
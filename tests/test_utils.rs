@@ -1,4 +1,4 @@
-use lineguard::checker::{CheckResult, Issue, IssueType};
+use lineguard::checker::{CheckResult, Issue, IssueType, Severity};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tempfile::TempDir;
@@ -118,12 +118,12 @@ impl TestableReporter for TestableJsonReporter {
                     .iter()
                     .map(|issue| {
                         json!({
-                            "type": match issue.issue_type {
-                                IssueType::MissingNewline => "missing_newline",
-                                IssueType::MultipleNewlines => "multiple_newlines",
-                                IssueType::TrailingSpace => "trailing_space",
-                            },
+                            "type": issue.issue_type.name(),
                             "line": issue.line,
+                            "column": issue.column.map(|c| c.character),
+                            "end_column": issue.end_column.map(|c| c.character),
+                            "byte_column": issue.column.map(|c| c.byte),
+                            "byte_end_column": issue.end_column.map(|c| c.byte),
                             "message": issue.message,
                         })
                     })
@@ -332,6 +332,10 @@ pub fn create_sample_issue(issue_type: IssueType, line: Option<usize>, message:
     Issue {
         issue_type,
         line,
+        end_line: None,
+        column: None,
+        end_column: None,
+        severity: Severity::Error,
         message: message.to_string(),
     }
 }
@@ -0,0 +1,89 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn write_trojan_source(temp_dir: &TempDir) {
+    std::fs::write(
+        temp_dir.path().join("access.js"),
+        "if (role !== \"user\u{202E} \u{2066}// admin\u{2069} \u{2066}\") {\n  grant();\n}\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_bidi_controls_reported_as_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    write_trojan_source(&temp_dir);
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("access.js");
+
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "  error   Line 1: U+202E RIGHT-TO-LEFT OVERRIDE found [LG014]",
+        ))
+        .stdout(predicate::str::contains(
+            "U+2069 POP DIRECTIONAL ISOLATE found [LG014]",
+        ));
+}
+
+#[test]
+fn test_unicode_hazard_columns_in_github_output() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("a.txt"), "naïve\u{200B}word\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--format").arg("github");
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "::warning file=a.txt,line=1,col=6,endColumn=7::U+200B ZERO WIDTH SPACE found [LG015]",
+    ));
+}
+
+#[test]
+fn test_unicode_hazards_can_be_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    write_trojan_source(&temp_dir);
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nunicode_hazards = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("access.js").arg("--fail-on").arg("info");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("All files passed"));
+}
+
+#[test]
+fn test_trailing_nbsp_is_named() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("a.md"), "text\u{00A0}\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.md");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Trailing spaces found (including U+00A0 NO-BREAK SPACE) [LG003]",
+        ))
+        .stdout(predicate::str::contains(
+            "U+00A0 NO-BREAK SPACE found [LG016]",
+        ));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.md").arg("--fix");
+    cmd.assert().success();
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("a.md")).unwrap(),
+        "text\n"
+    );
+}
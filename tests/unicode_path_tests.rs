@@ -42,11 +42,22 @@ fn test_unicode_file_names() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -99,11 +110,22 @@ fn test_unicode_directory_names() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -141,11 +163,22 @@ fn test_unicode_glob_patterns() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -208,11 +241,22 @@ fn test_unicode_in_ignore_patterns() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
@@ -261,11 +305,22 @@ fn test_mixed_unicode_and_ascii_paths() {
         extensions: None,
         no_newline_check: false,
         no_trailing_space: false,
+        max_line_length: None,
         fix: false,
         dry_run: false,
         from: None,
         to: None,
         no_hidden: false,
+        select: None,
+        extend_select: vec![],
+        ignore_rule: vec![],
+        fail_on: lineguard::Severity::Error,
+        write_baseline: None,
+        baseline: None,
+        report_unused_suppressions: false,
+        staged: false,
+        changed_lines_only: false,
+        no_ignore_vcs: false,
     };
 
     let config = Config::default();
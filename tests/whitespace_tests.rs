@@ -0,0 +1,113 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_whitespace_only_lines_reported_separately() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("a.txt"), "code \n   \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 1: Trailing spaces found [LG003]",
+        ))
+        .stdout(predicate::str::contains(
+            "Line 2: Whitespace-only line found [LG024]",
+        ));
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\ntrailing_spaces = false\nwhitespace_only_lines = true\n",
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[LG003]").not())
+        .stdout(predicate::str::contains("[LG024]"));
+}
+
+#[test]
+fn test_whitespace_only_lines_follow_trailing_spaces() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("a.txt"),
+        "code 
+   
+# lineguard-disable-next-line trailing-space
+  
+",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt").arg("--no-trailing-space");
+    cmd.assert().success();
+
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\ntrailing_spaces = false\n",
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert().success();
+
+    // A trailing-space suppression still covers whitespace-only lines
+    std::fs::remove_file(temp_dir.path().join(".lineguardrc")).unwrap();
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("a.txt");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Line 2: Whitespace-only line found [LG024]",
+        ))
+        .stdout(predicate::str::contains("Line 4:").not());
+}
+
+#[test]
+fn test_markdown_hard_breaks_only_in_markdown() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".lineguardrc"),
+        "[checks]\nmarkdown_hard_breaks = true\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("README.md"),
+        "one  \ntwo \nthree   \nfour\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("notes.txt"), "one  \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("README.md")
+        .arg("notes.txt")
+        .arg("--format")
+        .arg("github");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("file=README.md,line=1,").not())
+        .stdout(predicate::str::contains("file=README.md,line=2,"))
+        .stdout(predicate::str::contains("file=README.md,line=3,"))
+        .stdout(predicate::str::contains("file=notes.txt,line=1,"));
+
+    let mut cmd = cargo_bin_cmd!("lineguard");
+    cmd.current_dir(&temp_dir);
+    cmd.arg("README.md").arg("--fix");
+    cmd.assert().success();
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("README.md")).unwrap(),
+        "one  \ntwo\nthree\nfour\n"
+    );
+}